@@ -0,0 +1,14 @@
+/// How closely `pow`, `exp`, and `ln` approximate their true values.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Accuracy {
+    /// Compute the result exactly as Hyperdrive's FixedPointMath.sol does,
+    /// including its truncations, e.g., `exp` returns zero below `-42.14`.
+    /// Values are converted to 18 decimals for the computation and back to
+    /// their decimals after it.
+    #[default]
+    SolidityCompatible,
+    /// Compute the result with extended intermediates and enough series terms
+    /// that it's within one unit in the last place of the true value at the
+    /// value's decimals.
+    Precise,
+}
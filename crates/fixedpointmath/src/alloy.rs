@@ -0,0 +1,219 @@
+use alloc::string::ToString;
+
+use alloy_primitives::{aliases::I512, Sign, Signed, Uint, I256, U256, U512};
+#[cfg(feature = "ethers")]
+use ethers::types::I256 as EthersI256;
+
+use crate::{FixedPoint, FixedPointError, FixedPointValue, Result};
+
+// Conversions to and from the internal wide type //
+
+// NOTE: Both libraries store their values as little-endian `u64` limbs, so the
+// conversions only need to check that the value fits in the smaller type.
+
+fn from_wide<const BITS: usize, const LIMBS: usize>(
+    value: primitive_types::U512,
+) -> Option<Uint<BITS, LIMBS>> {
+    Uint::checked_from_limbs_slice(&value.0)
+}
+
+fn into_wide<const BITS: usize, const LIMBS: usize>(
+    value: Uint<BITS, LIMBS>,
+) -> primitive_types::U512 {
+    let mut limbs = [0; 8];
+    limbs[..LIMBS].copy_from_slice(value.as_limbs());
+    primitive_types::U512(limbs)
+}
+
+// FixedPointValue implementations //
+
+/// Implements `FixedPointValue` and conversions for the alloy signed and
+/// unsigned integers with the given number of bits.
+macro_rules! alloy_value_impls {
+    ($($uint:ident, $int:ident, $uint_decimals:expr, $int_decimals:expr);* $(;)?) => {
+        $(
+            impl FixedPointValue for $uint {
+                type Unsigned = primitive_types::U512;
+
+                const MIN: Option<Self> = Some($uint::ZERO);
+                const MAX: Option<Self> = Some($uint::MAX);
+                const MAX_DECIMALS: u8 = $uint_decimals;
+
+                fn from_unsigned(value: primitive_types::U512) -> Result<Self> {
+                    from_wide(value).ok_or_else(|| FixedPointError::OutOfRange {
+                        value: value.to_string(),
+                        target: stringify!($uint),
+                    })
+                }
+
+                fn to_unsigned(self) -> Result<primitive_types::U512> {
+                    Ok(into_wide(self))
+                }
+
+                fn from_u512(value: primitive_types::U512) -> Result<Self> {
+                    Self::from_unsigned(value)
+                }
+
+                fn to_u512(self) -> Result<primitive_types::U512> {
+                    self.to_unsigned()
+                }
+
+                fn is_signed() -> bool {
+                    false
+                }
+            }
+
+            impl FixedPointValue for $int {
+                type Unsigned = primitive_types::U512;
+
+                const MIN: Option<Self> = Some($int::MIN);
+                const MAX: Option<Self> = Some($int::MAX);
+                const MAX_DECIMALS: u8 = $int_decimals;
+
+                fn from_unsigned(value: primitive_types::U512) -> Result<Self> {
+                    from_wide(value)
+                        .and_then(|abs| Signed::checked_from_sign_and_abs(Sign::Positive, abs))
+                        .ok_or_else(|| FixedPointError::OutOfRange {
+                            value: value.to_string(),
+                            target: stringify!($int),
+                        })
+                }
+
+                fn to_unsigned(self) -> Result<primitive_types::U512> {
+                    if self.is_negative() {
+                        return Err(FixedPointError::NegativeToUnsigned {
+                            value: self.to_string(),
+                            target: "U512",
+                        });
+                    }
+                    Ok(into_wide(self.into_raw()))
+                }
+
+                fn from_u512(value: primitive_types::U512) -> Result<Self> {
+                    Self::from_unsigned(value)
+                }
+
+                fn to_u512(self) -> Result<primitive_types::U512> {
+                    self.to_unsigned()
+                }
+
+                fn unsigned_abs(self) -> primitive_types::U512 {
+                    into_wide($int::unsigned_abs(self))
+                }
+            }
+
+            impl<const D: u8> From<FixedPoint<$uint, D>> for $uint {
+                fn from(f: FixedPoint<$uint, D>) -> Self {
+                    f.raw()
+                }
+            }
+
+            impl<const D: u8> From<FixedPoint<$int, D>> for $int {
+                fn from(f: FixedPoint<$int, D>) -> Self {
+                    f.raw()
+                }
+            }
+
+            crate::fixed_point_value_impl!(@try_from $uint => u32 | i64 | u64 | u128 | i128 | $int);
+            crate::fixed_point_value_impl!(@try_from $int => u32 | i64 | u64 | u128 | i128 | $uint);
+
+            impl<const D: u8> From<FixedPoint<primitive_types::$uint, D>> for FixedPoint<$uint, D> {
+                fn from(f: FixedPoint<primitive_types::$uint, D>) -> Self {
+                    let raw = from_wide(f.raw().into()).unwrap();
+                    FixedPoint::new(raw)
+                }
+            }
+
+            impl<const D: u8> From<FixedPoint<$uint, D>> for FixedPoint<primitive_types::$uint, D> {
+                fn from(f: FixedPoint<$uint, D>) -> Self {
+                    let raw = primitive_types::$uint::try_from(into_wide(f.raw())).unwrap();
+                    FixedPoint::new(raw)
+                }
+            }
+        )*
+    };
+}
+
+alloy_value_impls!(
+    U256, I256, 77, 76;
+    U512, I512, 154, 153;
+);
+
+// Conversions between the alloy and ethers flavors of `FixedPoint` //
+
+// NOTE: The ethers `U256` is the same type as the internal `U256`, so its
+// conversions are implemented with the other unsigned types above.
+
+#[cfg(feature = "ethers")]
+impl<const D: u8> From<FixedPoint<EthersI256, D>> for FixedPoint<I256, D> {
+    fn from(f: FixedPoint<EthersI256, D>) -> Self {
+        let raw = U256::from_limbs(f.raw().into_raw().0);
+        FixedPoint::new(I256::from_raw(raw))
+    }
+}
+
+#[cfg(feature = "ethers")]
+impl<const D: u8> From<FixedPoint<I256, D>> for FixedPoint<EthersI256, D> {
+    fn from(f: FixedPoint<I256, D>) -> Self {
+        let raw = primitive_types::U256(f.raw().into_raw().into_limbs());
+        FixedPoint::new(EthersI256::from_raw(raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256 as EthersI256, U256 as EthersU256};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, fixed_i256};
+
+    #[test]
+    fn test_alloy_math() {
+        let a: FixedPoint<U256> = fixed!(1.5e18);
+        let b: FixedPoint<U256> = fixed!(2e18);
+        assert_eq!(a.mul_down(b), fixed!(3e18));
+        assert_eq!(a.div_up(b), fixed!(0.75e18));
+        let ethers_b = FixedPoint::<EthersU256>::from(b);
+        assert_eq!(b.pow(b).unwrap(), ethers_b.pow(ethers_b).unwrap().into());
+
+        let c: FixedPoint<I256> = fixed!(-1.5e18);
+        assert_eq!(c.mul_down(b.change_type().unwrap()), fixed!(-3e18));
+        assert_eq!(c.abs(), fixed!(1.5e18));
+        assert_eq!(
+            FixedPoint::<I256>::MIN.unsigned_abs().raw(),
+            primitive_types::U512::one() << 255
+        );
+        assert!(FixedPoint::<U256>::try_from(c.raw()).is_err());
+
+        // 512-bit values can exceed the 256-bit range.
+        let d: FixedPoint<I512> = fixed!(-1e90);
+        let e: FixedPoint<I512> = fixed!(2e18);
+        assert_eq!(d.mul_down(e), fixed!(-2e90));
+        assert_eq!(d.div_down(e), fixed!(-0.5e90));
+        assert_eq!(d.unsigned_abs(), fixed!(1e90));
+        assert!(FixedPoint::<I512>::MIN.checked_sub(e).is_err());
+        assert!(FixedPoint::<I256>::from_sign_and_abs(d.sign(), d.unsigned_abs().raw()).is_err());
+    }
+
+    #[test]
+    fn fuzz_ethers_alloy_conversions() {
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let uint: FixedPoint<EthersU256> = rng.gen();
+            let alloy_uint = FixedPoint::<U256>::from(uint);
+            assert_eq!(alloy_uint.to_string(), uint.to_string());
+            assert_eq!(FixedPoint::<EthersU256>::from(alloy_uint), uint);
+
+            let int: FixedPoint<EthersI256> = rng.gen();
+            let alloy_int = FixedPoint::<I256>::from(int);
+            assert_eq!(alloy_int.to_string(), int.to_string());
+            assert_eq!(FixedPoint::<EthersI256>::from(alloy_int), int);
+        }
+
+        let uint = FixedPoint::<EthersU256, 6>::new(1_500_000_u128);
+        assert_eq!(FixedPoint::<U256, 6>::from(uint).to_string(), "1.500000");
+        let int = fixed_i256!(-1.5e18);
+        assert_eq!(FixedPoint::<I256>::from(int), fixed!(-1.5e18));
+    }
+}
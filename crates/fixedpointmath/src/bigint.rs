@@ -0,0 +1,315 @@
+use alloc::{format, vec::Vec};
+
+use num_bigint::{BigInt, BigUint};
+use primitive_types::U512;
+
+use crate::{wide::WideValue, FixedPoint, FixedPointError, FixedPointValue, Result, UnsignedValue};
+
+// NOTE: The arbitrary-precision integers are unbounded, so they don't have a
+// `MAX` and the math on them never overflows. `BigUint` is still bounded below
+// by `0` so it can't represent negative values.
+
+// FixedPointValue implementations //
+
+impl FixedPointValue for BigUint {
+    type Unsigned = BigUint;
+
+    const MIN: Option<Self> = Some(BigUint::ZERO);
+    const MAX: Option<Self> = None;
+    const MAX_DECIMALS: u8 = u8::MAX;
+
+    fn from_unsigned(value: BigUint) -> Result<Self> {
+        Ok(value)
+    }
+
+    fn to_unsigned(self) -> Result<BigUint> {
+        Ok(self)
+    }
+}
+
+impl UnsignedValue for BigUint {
+    type Wide = BigUint;
+    type Extended = BigUint;
+
+    fn from_limbs(limbs: &[u64]) -> Option<Self> {
+        let digits = limbs
+            .iter()
+            .flat_map(|&limb| [limb as u32, (limb >> 32) as u32])
+            .collect();
+        Some(BigUint::new(digits))
+    }
+
+    fn into_limbs(self) -> Vec<u64> {
+        self.to_u64_digits()
+    }
+
+    fn try_from_wide(value: BigUint) -> Option<Self> {
+        Some(value)
+    }
+
+    fn try_from_extended(value: BigUint) -> Option<Self> {
+        Some(value)
+    }
+}
+
+impl WideValue for BigUint {
+    fn one() -> Self {
+        BigUint::from(1_u8)
+    }
+
+    fn from_u64(value: u64) -> Self {
+        BigUint::from(value)
+    }
+
+    fn exp10(n: usize) -> Self {
+        BigUint::from(10_u8).pow(n as u32)
+    }
+
+    fn pow2(n: usize) -> Self {
+        BigUint::from(1_u8) << n
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == BigUint::ZERO
+    }
+
+    fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    fn bits(&self) -> usize {
+        BigUint::bits(self) as usize
+    }
+
+    fn low_u64(&self) -> u64 {
+        self.iter_u64_digits().next().unwrap_or(0)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Some(self * other)
+    }
+
+    fn div_mod(self, other: Self) -> (Self, Self) {
+        (&self / &other, self % other)
+    }
+}
+
+impl FixedPointValue for BigInt {
+    type Unsigned = BigUint;
+
+    const MIN: Option<Self> = None;
+    const MAX: Option<Self> = None;
+    const MAX_DECIMALS: u8 = u8::MAX;
+
+    fn from_unsigned(value: BigUint) -> Result<Self> {
+        Ok(BigInt::from(value))
+    }
+
+    fn to_unsigned(self) -> Result<BigUint> {
+        if self.is_negative() {
+            return Err(FixedPointError::NegativeToUnsigned {
+                value: format!("{self}"),
+                target: "BigUint",
+            });
+        }
+        Ok(self.into_parts().1)
+    }
+
+    fn is_signed() -> bool {
+        true
+    }
+
+    fn unsigned_abs(self) -> BigUint {
+        self.into_parts().1
+    }
+}
+
+impl<const D: u8> From<FixedPoint<BigUint, D>> for BigUint {
+    fn from(f: FixedPoint<BigUint, D>) -> Self {
+        f.raw()
+    }
+}
+
+impl<const D: u8> From<FixedPoint<BigInt, D>> for BigInt {
+    fn from(f: FixedPoint<BigInt, D>) -> Self {
+        f.raw()
+    }
+}
+
+crate::fixed_point_value_impl!(@from BigUint => u32 | u64 | u128);
+crate::fixed_point_value_impl!(@try_from BigUint => i64 | i128 | BigInt);
+crate::fixed_point_value_impl!(@from BigInt => u32 | i64 | u64 | u128 | i128 | BigUint);
+
+// Conversions between the fixed-size and arbitrary-precision flavors of
+// `FixedPoint` //
+
+impl<T: FixedPointValue<Unsigned = U512>, const D: u8> From<FixedPoint<T, D>>
+    for FixedPoint<BigInt, D>
+{
+    fn from(f: FixedPoint<T, D>) -> Self {
+        let abs = BigUint::from_limbs(&f.raw().unsigned_abs().0).unwrap();
+        FixedPoint::from_sign_and_abs(f.sign(), abs).unwrap()
+    }
+}
+
+impl<T: FixedPointValue<Unsigned = U512>, const D: u8> TryFrom<FixedPoint<BigInt, D>>
+    for FixedPoint<T, D>
+{
+    type Error = FixedPointError;
+
+    fn try_from(f: FixedPoint<BigInt, D>) -> Result<Self> {
+        let abs = f.unsigned_abs().to_u512()?;
+        FixedPoint::<T, D>::from_sign_and_abs(f.sign(), abs)
+    }
+}
+
+/// Implements conversions between `FixedPoint<BigInt>` and native integers.
+macro_rules! native_bigint_conversions {
+    ($($t:ty),*) => {
+        $(
+            impl<const D: u8> From<FixedPoint<$t, D>> for FixedPoint<BigInt, D> {
+                fn from(f: FixedPoint<$t, D>) -> Self {
+                    let abs = BigUint::from(f.raw().unsigned_abs());
+                    FixedPoint::from_sign_and_abs(f.sign(), abs).unwrap()
+                }
+            }
+
+            impl<const D: u8> TryFrom<FixedPoint<BigInt, D>> for FixedPoint<$t, D> {
+                type Error = FixedPointError;
+
+                fn try_from(f: FixedPoint<BigInt, D>) -> Result<Self> {
+                    let abs = f.unsigned_abs().raw().to_u128()?;
+                    FixedPoint::from_sign_and_abs(
+                        f.sign(),
+                        <$t as FixedPointValue>::Unsigned::from_u128(abs)?,
+                    )
+                }
+            }
+        )*
+    };
+}
+
+native_bigint_conversions!(u32, i64, u64, i128, u128);
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+    use crate::{fixed, RoundingMode};
+
+    #[test]
+    fn test_bigint_math() {
+        // Values aren't limited to 512 bits.
+        let a: FixedPoint<BigInt> = fixed!(-1e200);
+        let b: FixedPoint<BigInt> = fixed!(1e300);
+        assert_eq!(a.clone().mul_down(b.clone()), fixed!(-1e482));
+        assert_eq!(b.clone().div_up(a.clone()), fixed!(-1e118));
+        assert_eq!(a.clone().abs_diff(b.clone()), fixed!(1e300) + fixed!(1e200));
+        assert_eq!(
+            a.clone().to_string(),
+            format!("-1{}.{}", "0".repeat(182), "0".repeat(18))
+        );
+
+        // The math can't overflow.
+        assert_eq!(
+            b.clone().saturating_mul_down(b.clone()),
+            b.clone().mul_down(b.clone())
+        );
+        assert_eq!(b.clone().wrapping_add(b.clone()), fixed!(2e300));
+        assert!(TryInto::<FixedPoint<U256>>::try_into(b.clone()).is_err());
+        assert!(FixedPoint::<i128>::try_from(b.clone()).is_err());
+
+        // Rounding and rescaling work the same as the fixed-size types.
+        let c: FixedPoint<BigInt> = fixed!(-1.5e18);
+        let c_i64 = FixedPoint::<i64>::try_from(c.clone()).unwrap();
+        assert_eq!(c_i64.raw(), -1_500_000_000_000_000_000);
+        assert_eq!(FixedPoint::<BigInt>::from(c_i64), c);
+        let d: FixedPoint<BigInt, 0> = c.clone().rescale(RoundingMode::HalfEven).unwrap();
+        assert_eq!(d.raw(), BigInt::from(-2));
+        let e: FixedPoint<BigInt, 200> = c.clone().rescale(RoundingMode::HalfEven).unwrap();
+        assert_eq!(e.to_string().len(), 203);
+
+        // Parsing caps the exponent so huge ones can't take unbounded time.
+        assert_eq!(
+            "1e9982"
+                .parse::<FixedPoint<BigInt>>()
+                .unwrap()
+                .to_string()
+                .len(),
+            10_002
+        );
+        assert!(matches!(
+            "1e4294967295".parse::<FixedPoint<BigInt>>(),
+            Err(FixedPointError::OutOfRange { .. })
+        ));
+
+        // Roots aren't limited by the size of an extended type.
+        assert_eq!(b.clone().sqrt_down().unwrap(), fixed!(1e159));
+        let one: FixedPoint<BigInt> = fixed!(1e18);
+        assert_eq!(
+            one.nth_root(100, RoundingMode::Floor).unwrap(),
+            fixed!(1e18)
+        );
+        assert!(a.clone().sqrt_up().is_err());
+        assert_eq!(
+            b.clone().pow_int(3, RoundingMode::Floor).unwrap(),
+            b.clone().mul_down(b.clone()).mul_down(b.clone())
+        );
+
+        // So are exponentials, which keep their relative precision.
+        assert_eq!(b.clone().log10().unwrap(), fixed!(282e18));
+        let x: FixedPoint<BigInt> = fixed!(1_000e18);
+        let exp = x.exp().unwrap().to_string();
+        assert!(exp.starts_with("1970071114017046"));
+        assert_eq!(exp.len(), 435 + 19);
+
+        // Conversions to `f64` are correctly rounded, or infinite beyond its
+        // range.
+        assert_eq!(a.to_f64(), -1e182);
+        assert_eq!(b.clone().mul_down(b.clone()).to_f64(), f64::INFINITY);
+        assert_eq!(FixedPoint::<BigInt, 255>::new(1).to_f64(), 1e-255);
+        let float = FixedPoint::<BigInt>::from_f64(1e300, RoundingMode::HalfEven).unwrap();
+        assert_eq!(float.to_f64(), 1e300);
+
+        // `BigUint` is still bounded below by `0`.
+        assert!(FixedPoint::<BigUint>::from_dec_str("-1").is_err());
+        assert!(FixedPoint::<BigUint>::new(1_u128)
+            .checked_sub(FixedPoint::new(2_u128))
+            .is_err());
+        assert_eq!(
+            FixedPoint::<BigUint>::new(1_u128).saturating_sub(FixedPoint::new(2_u128)),
+            FixedPoint::new(0_u128)
+        );
+    }
+
+    #[test]
+    fn fuzz_bigint_reference() {
+        // The arbitrary-precision math agrees with the fixed-size math whenever
+        // the result fits.
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let a: FixedPoint<I256> = rng.gen();
+            let b: FixedPoint<I256> = rng.gen();
+            let big_a = FixedPoint::<BigInt>::from(a);
+            let big_b = FixedPoint::<BigInt>::from(b);
+            assert_eq!(big_a.to_string(), a.to_string());
+            assert_eq!(big_a.clone().try_into(), Ok(a));
+
+            if let Ok(sum) = a.checked_add(b) {
+                assert_eq!(big_a.clone() + big_b.clone(), sum.into());
+            }
+            if let Ok(product) = a.checked_mul_down(b) {
+                assert_eq!(big_a.clone().mul_down(big_b.clone()), product.into());
+            }
+            if let Ok(quotient) = a.checked_div_up(b) {
+                assert_eq!(big_a.div_up(big_b), quotient.into());
+            }
+
+            let c: FixedPoint<U256> = rng.gen();
+            let big_c = FixedPoint::<BigUint>::new(BigUint::from_limbs(&c.raw().0).unwrap());
+            assert_eq!(big_c.to_string(), c.to_string());
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+/// A `Result` type that defaults to `FixedPointError` for its error.
+pub type Result<T, E = FixedPointError> = core::result::Result<T, E>;
+
+/// An arithmetic operation that can fail.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operation {
+    Add,
+    Sub,
+    MulDiv,
+    /// Dividing a value into an integer quotient, e.g., with `div_euclid`.
+    Div,
+    /// Taking the remainder of a division.
+    Rem,
+    Pow,
+    /// Scaling a value to the given number of decimals.
+    Rescale(u8),
+    /// Rounding a value to a multiple of a step.
+    Quantize,
+    /// Taking the given root of a value, e.g., `2` for the square root.
+    Root(u32),
+    /// Taking the logarithm of a value in a base.
+    Log,
+}
+
+/// The error returned by fallible `FixedPoint` operations and conversions.
+///
+/// Operands and values are carried as strings since they can be of any
+/// `FixedPointValue` type.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FixedPointError {
+    /// The result of an operation doesn't fit in the underlying type.
+    Overflow {
+        operation: Operation,
+        operands: Vec<String>,
+    },
+    /// An operation divided by zero.
+    DivisionByZero {
+        operation: Operation,
+        operands: Vec<String>,
+    },
+    /// A value is out of the range of the type it's converted to.
+    OutOfRange { value: String, target: &'static str },
+    /// A negative value was converted to an unsigned type.
+    NegativeToUnsigned { value: String, target: &'static str },
+    /// A raw value's decimals don't match the `FixedPoint`'s `D`.
+    InvalidDecimals { decimals: u8, expected: u8 },
+    /// The natural logarithm of zero or a negative number was requested.
+    LnNonPositive { value: String },
+    /// A root of a negative number was requested.
+    NegativeRoot { value: String },
+    /// A negative number was raised to a power whose result isn't real, i.e.,
+    /// an exponent that isn't a fraction with an odd denominator.
+    NonRealPower { base: String, exponent: String },
+    /// The input to an exponential is too large for the result to fit in the
+    /// underlying type.
+    ExpOverflow { value: String },
+    /// A NaN or infinite float was converted to a `FixedPoint`.
+    NonFinite { value: String },
+    /// A string couldn't be parsed into a number. The position is the byte
+    /// offset in the input where parsing failed.
+    Parse {
+        input: String,
+        position: usize,
+        reason: String,
+    },
+}
+
+impl FixedPointError {
+    pub(crate) fn overflow<O: ToString>(operation: Operation, operands: &[O]) -> Self {
+        FixedPointError::Overflow {
+            operation,
+            operands: operands.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    pub(crate) fn division_by_zero<O: ToString>(operation: Operation, operands: &[O]) -> Self {
+        FixedPointError::DivisionByZero {
+            operation,
+            operands: operands.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    pub(crate) fn parse(input: &str, position: usize, reason: impl ToString) -> Self {
+        FixedPointError::Parse {
+            input: input.to_string(),
+            position,
+            reason: reason.to_string(),
+        }
+    }
+
+    /// Wraps the error in an [`eyre::Report`], e.g., for use with `map_err`
+    /// where the conversion can't be inferred.
+    #[cfg(feature = "eyre")]
+    pub fn into_report(self) -> eyre::Report {
+        eyre::Report::new(self)
+    }
+}
+
+/// Writes an operation with its operands as an expression, e.g., `a * b / c`.
+fn fmt_expression(
+    f: &mut fmt::Formatter,
+    operation: &Operation,
+    operands: &[String],
+) -> fmt::Result {
+    match (operation, operands) {
+        (Operation::Add, [a, b]) => write!(f, "{a} + {b}"),
+        (Operation::Sub, [a, b]) => write!(f, "{a} - {b}"),
+        (Operation::MulDiv, [a, b, c]) => write!(f, "{a} * {b} / {c}"),
+        (Operation::Div, [a, b]) => write!(f, "{a} / {b}"),
+        (Operation::Rem, [a, b]) => write!(f, "{a} % {b}"),
+        (Operation::Pow, [a, b]) => write!(f, "{a} ^ {b}"),
+        (Operation::Rescale(decimals), [a]) => write!(f, "{a} scaled to {decimals} decimals"),
+        (Operation::Quantize, [a, b]) => write!(f, "{a} rounded to a multiple of {b}"),
+        (Operation::Root(n), [a]) => write!(f, "{a} ^ (1 / {n})"),
+        (Operation::Log, [a, b]) => write!(f, "log_{b}({a})"),
+        (operation, operands) => write!(f, "{operation:?}({})", operands.join(", ")),
+    }
+}
+
+impl fmt::Display for FixedPointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            FixedPointError::Overflow {
+                operation,
+                operands,
+            } => {
+                write!(f, "FixedPoint operation overflowed: ")?;
+                fmt_expression(f, operation, operands)
+            }
+            FixedPointError::DivisionByZero {
+                operation,
+                operands,
+            } => {
+                write!(f, "Cannot divide by zero: ")?;
+                fmt_expression(f, operation, operands)
+            }
+            FixedPointError::OutOfRange { value, target } => {
+                write!(f, "Value {value} is out of range for {target}.")
+            }
+            FixedPointError::NegativeToUnsigned { value, target } => {
+                write!(
+                    f,
+                    "Cannot convert negative value {value} to unsigned {target}."
+                )
+            }
+            FixedPointError::InvalidDecimals { decimals, expected } => {
+                write!(
+                    f,
+                    "Invalid FixedPoint decimals {decimals}, expected {expected}."
+                )
+            }
+            FixedPointError::LnNonPositive { value } => {
+                write!(f, "Cannot calculate ln of negative number or zero: {value}")
+            }
+            FixedPointError::NegativeRoot { value } => {
+                write!(f, "Cannot calculate root of negative number: {value}")
+            }
+            FixedPointError::NonRealPower { base, exponent } => {
+                write!(
+                    f,
+                    "Cannot raise negative number {base} to the power of {exponent}, the result isn't real."
+                )
+            }
+            FixedPointError::ExpOverflow { value } => write!(f, "Invalid exponent {value}"),
+            FixedPointError::NonFinite { value } => {
+                write!(f, "Cannot convert non-finite value {value} to FixedPoint.")
+            }
+            FixedPointError::Parse {
+                input,
+                position,
+                reason,
+            } => {
+                write!(f, "Failed to parse {input:?} at byte {position}: {reason}")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FixedPointError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            FixedPointError::overflow(Operation::MulDiv, &["1.0", "2.0", "0.5"]).to_string(),
+            "FixedPoint operation overflowed: 1.0 * 2.0 / 0.5"
+        );
+        assert_eq!(
+            FixedPointError::division_by_zero(Operation::Pow, &["0.0", "-1.0"]).to_string(),
+            "Cannot divide by zero: 0.0 ^ -1.0"
+        );
+        assert_eq!(
+            FixedPointError::division_by_zero(Operation::Rem, &["1.0", "0.0"]).to_string(),
+            "Cannot divide by zero: 1.0 % 0.0"
+        );
+        assert_eq!(
+            FixedPointError::overflow(Operation::Rescale(6), &["1.0"]).to_string(),
+            "FixedPoint operation overflowed: 1.0 scaled to 6 decimals"
+        );
+        assert_eq!(
+            FixedPointError::overflow(Operation::Quantize, &["-1.5", "0.5"]).to_string(),
+            "FixedPoint operation overflowed: -1.5 rounded to a multiple of 0.5"
+        );
+        assert_eq!(
+            FixedPointError::division_by_zero(Operation::Root(0), &["4.0"]).to_string(),
+            "Cannot divide by zero: 4.0 ^ (1 / 0)"
+        );
+        assert_eq!(
+            FixedPointError::division_by_zero(Operation::Log, &["2.0", "1.0"]).to_string(),
+            "Cannot divide by zero: log_1.0(2.0)"
+        );
+        assert_eq!(
+            FixedPointError::NonRealPower {
+                base: "-8.0".into(),
+                exponent: "0.5".into()
+            }
+            .to_string(),
+            "Cannot raise negative number -8.0 to the power of 0.5, the result isn't real."
+        );
+        assert_eq!(
+            FixedPointError::NonFinite {
+                value: "NaN".into()
+            }
+            .to_string(),
+            "Cannot convert non-finite value NaN to FixedPoint."
+        );
+        assert_eq!(
+            FixedPointError::parse("1x", 1, "unexpected character 'x'").to_string(),
+            r#"Failed to parse "1x" at byte 1: unexpected character 'x'"#
+        );
+    }
+}
@@ -1,101 +1,203 @@
-use std::fmt::{self, Debug};
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    str::FromStr,
+};
 
-use ethers::types::{I256, U256};
-use eyre::{bail, eyre, Result};
+#[cfg(feature = "ethers")]
+use ethers::types::I256;
+use primitive_types::{U256, U512};
 
-use crate::{sign::FixedPointSign, utils::u256_from_str, value::FixedPointValue};
+use crate::{
+    error::{FixedPointError, Operation, Result},
+    parse::parse,
+    rounding::RoundingMode,
+    sign::FixedPointSign,
+    value::{FixedPointValue, UnsignedValue},
+    wide::{Extended, Wide, WideValue},
+};
 
-/// A generic fixed point type built on ethers-rs.
-#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
-pub struct FixedPoint<T: FixedPointValue> {
+/// The number of decimal places used by `FixedPoint` instances when the scale
+/// isn't specified, i.e., the default for `D`.
+pub const DEFAULT_DECIMALS: u8 = 18;
+
+/// The bits of an `f64`'s fraction, i.e., its mantissa without the implicit
+/// leading bit.
+const FRACTION_MASK: u64 = (1 << 52) - 1;
+
+/// A rounded `f64` mantissa that carried into the next power of 2.
+const MANTISSA_CARRY: u64 = 1 << 53;
+
+/// A generic fixed point type built on 256-bit integer math.
+///
+/// The const parameter `D` is the number of decimal places values of the type
+/// are created with, e.g., `FixedPoint<U256, 6>` for USDC amounts. Since
+/// operators are only implemented between values of the same type, mixing a
+/// 6-decimal and an 18-decimal amount is a compile error rather than a silently
+/// wrong number:
+///
+/// ```compile_fail
+/// use ethers::types::U256;
+/// use fixedpointmath::FixedPoint;
+///
+/// let usdc = FixedPoint::<U256, 6>::new(1_500_000_u128);
+/// let weth = FixedPoint::<U256>::new(1_500_000_000_000_000_000_u128);
+/// let _ = usdc + weth;
+/// ```
+///
+/// Use [`FixedPoint::rescale`] to explicitly convert between scales. The raw
+/// value is always scaled by `D`, so [`FixedPoint::decimals`] is always `D`.
+#[derive(Clone, Copy)]
+pub struct FixedPoint<T: FixedPointValue, const D: u8 = DEFAULT_DECIMALS> {
     raw: T,
-    decimals: u8,
 }
 
-impl<T: FixedPointValue> FixedPoint<T> {
-    pub const MIN: Self = Self {
-        raw: T::MIN,
-        decimals: T::MAX_DECIMALS,
+// The bounds are only available as constants for `Copy` types since
+// evaluating `T::MIN` and `T::MAX` in a constant requires that they can't have
+// a destructor. All of the bounded `FixedPointValue` types are `Copy`.
+impl<T: FixedPointValue + Copy, const D: u8> FixedPoint<T, D> {
+    /// The minimum value of the type.
+    ///
+    /// Fails to compile if `T` is unbounded.
+    pub const MIN: Self = {
+        let () = Self::VALID_DECIMALS;
+        match T::MIN {
+            Some(raw) => Self { raw },
+            None => panic!("FixedPoint::MIN is undefined for unbounded types."),
+        }
     };
 
-    pub const MAX: Self = Self {
-        raw: T::MAX,
-        decimals: T::MAX_DECIMALS,
+    /// The maximum value of the type.
+    ///
+    /// Fails to compile if `T` is unbounded.
+    pub const MAX: Self = {
+        let () = Self::VALID_DECIMALS;
+        match T::MAX {
+            Some(raw) => Self { raw },
+            None => panic!("FixedPoint::MAX is undefined for unbounded types."),
+        }
     };
+}
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Fails to compile if `D` is greater than `T::MAX_DECIMALS`.
+    const VALID_DECIMALS: () = assert!(
+        D <= T::MAX_DECIMALS,
+        "FixedPoint decimals must be less than or equal to the underlying type's MAX_DECIMALS."
+    );
 
     // Constructors //
 
+    /// Creates a new fixed point number from a raw value scaled by `D`
+    /// decimals.
     pub fn new<V: Into<T>>(value: V) -> Self {
-        Self {
-            raw: value.into(),
-            // TODO: Add support for variable decimals.
-            decimals: T::MAX_DECIMALS,
+        let () = Self::VALID_DECIMALS;
+        Self { raw: value.into() }
+    }
+
+    /// Creates a new fixed point number from a raw value scaled by `decimals`,
+    /// returning an error unless `decimals` is `D`. Use
+    /// [`FixedPoint::rescale`] to convert a value to another scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ethers::types::U256;
+    /// use fixedpointmath::FixedPoint;
+    ///
+    /// // 1.5 USDC
+    /// let usdc = FixedPoint::<U256, 6>::with_decimals(1_500_000_u128, 6).unwrap();
+    /// assert_eq!(usdc.to_string(), "1.500000");
+    /// assert!(FixedPoint::<U256, 6>::with_decimals(1_500_000_u128, 18).is_err());
+    /// ```
+    pub fn with_decimals<V: Into<T>>(value: V, decimals: u8) -> Result<Self> {
+        if decimals != D {
+            return Err(FixedPointError::InvalidDecimals {
+                decimals,
+                expected: D,
+            });
         }
+        Ok(Self::new(value))
     }
 
     pub fn try_from<V: TryInto<T> + Debug>(value: V) -> Result<Self> {
         // Convert the value to a Debug string before moving it incase the
         // conversion fails.
         let value_debug = format!("{:?}", value);
-        let value = value.try_into().map_err(|_| {
-            eyre!(
-                r#"Failed to convert value to underlying FixedPointValue type:
-    value: {value_debug}
-    Underlying range: {min:?} to {max:?}
-"#,
-                min = Self::MIN,
-                max = Self::MAX,
-            )
+        let value = value.try_into().map_err(|_| FixedPointError::OutOfRange {
+            value: value_debug,
+            target: core::any::type_name::<T>(),
         })?;
 
         Ok(Self::new(value))
     }
 
-    pub fn from_sign_and_abs(sign: FixedPointSign, abs: U256) -> Result<Self> {
+    pub fn from_sign_and_abs<A: Into<T::Unsigned>>(sign: FixedPointSign, abs: A) -> Result<Self> {
+        let abs = abs.into();
         Ok(match sign {
-            FixedPointSign::Positive => Self::new(T::from_u256(abs)?),
+            FixedPointSign::Positive => Self::new(T::from_unsigned(abs)?),
             FixedPointSign::Negative => {
-                if abs == T::MIN.unsigned_abs() {
+                if let Some(min) = T::MIN.filter(|min| min.clone().unsigned_abs() == abs) {
                     // NOTE: The absolute MIN value of a two's-complement
                     // integer is 1 greater than its MAX. Attempting to create a
                     // positive `T` instance with this value then flipping the
                     // sign after the fact will overflow, so we just return the
                     // MIN value directly in this case.
-                    Self::MIN
+                    Self::new(min)
+                } else if !T::is_signed() {
+                    return Err(FixedPointError::NegativeToUnsigned {
+                        value: format!("-{abs:?}"),
+                        target: core::any::type_name::<T>(),
+                    });
                 } else {
-                    let raw = T::from_u256(abs)?.flip_sign();
+                    let raw = T::from_unsigned(abs)?.flip_sign();
                     Self::new(raw)
                 }
             }
         })
     }
 
+    /// Parses a string into the raw value of a fixed point number, e.g.,
+    /// `"1.5e18"` or `"0x14d1120d7b160000"` for `1.5` with 18 decimals.
+    ///
+    /// The string must be an integer after applying its exponent, see
+    /// [`FromStr`](#impl-FromStr-for-FixedPoint<T,+D>) for the full grammar
+    /// and to parse the scaled value instead.
     pub fn from_dec_str(s: &str) -> Result<Self> {
-        if s.starts_with('-') {
-            Self::from_sign_and_abs(FixedPointSign::Negative, u256_from_str(&s[1..])?)
-        } else {
-            Self::from_sign_and_abs(FixedPointSign::Positive, u256_from_str(s)?)
-        }
+        parse::<T>(s, 0).map(Self::new)
     }
 
+    /// Returns the bound of `T` in the direction of the given sign.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is unbounded.
     pub fn saturate_sign(sign: FixedPointSign) -> Self {
-        match sign {
-            FixedPointSign::Positive => Self::MAX,
-            FixedPointSign::Negative => Self::MIN,
-        }
+        let bound = match sign {
+            FixedPointSign::Positive => T::MAX,
+            FixedPointSign::Negative => T::MIN,
+        };
+        Self::new(bound.expect("unbounded types can't be saturated"))
     }
 
     pub fn zero() -> Self {
-        Self::new(0)
+        Self::new(T::default())
     }
 
     /// One with the same scale as this fixed point number, i.e., `1.0`.
     ///
     /// # Panics
     ///
-    /// Panics if `10` to the power of `self.decimals()` overflows `T`.
+    /// Panics if `10` to the power of `D` overflows `T`.
     pub fn one(&self) -> Self {
-        Self::try_from(10_u128.pow(self.decimals().into())).unwrap()
+        let one = T::Unsigned::try_from_wide(Wide::<T>::exp10(D.into()))
+            .and_then(|one| T::from_unsigned(one).ok())
+            .unwrap();
+        Self::new(one)
     }
 
     // Getters //
@@ -103,11 +205,13 @@ impl<T: FixedPointValue> FixedPoint<T> {
     /// Returns the underlying raw value of the fixed point number, e.g., `U256`
     /// for `FixedPoint<U256>`.
     pub fn raw(&self) -> T {
-        self.raw
+        self.raw.clone()
     }
 
+    /// Returns the number of decimal places of the raw value, which is always
+    /// `D`.
     pub fn decimals(&self) -> u8 {
-        self.decimals
+        D
     }
 
     pub fn sign(&self) -> FixedPointSign {
@@ -120,7 +224,7 @@ impl<T: FixedPointValue> FixedPoint<T> {
     // Predicates //
 
     pub fn is_negative(&self) -> bool {
-        self.raw().is_negative()
+        self.raw.is_negative()
     }
 
     pub fn is_positive(&self) -> bool {
@@ -128,13 +232,72 @@ impl<T: FixedPointValue> FixedPoint<T> {
     }
 
     pub fn is_zero(&self) -> bool {
-        self.raw().is_zero()
+        self.raw.is_zero()
+    }
+
+    // Scaling //
+
+    /// Returns self if `decimals` is `D`, or an error since a `FixedPoint<T, D>`
+    /// can't hold a value scaled by other decimals. Use
+    /// [`FixedPoint::rescale`] to convert a value to another scale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_u256;
+    ///
+    /// assert_eq!(fixed_u256!(1.5e18).change_decimals(18), Ok(fixed_u256!(1.5e18)));
+    /// assert!(fixed_u256!(1.5e18).change_decimals(6).is_err());
+    /// ```
+    pub fn change_decimals(self, decimals: u8) -> Result<Self> {
+        if decimals != D {
+            return Err(FixedPointError::InvalidDecimals {
+                decimals,
+                expected: D,
+            });
+        }
+        Ok(self)
+    }
+
+    /// Converts this instance to a `FixedPoint` type with `D2` decimals,
+    /// rounding with the given mode when reducing the number of decimals.
+    /// Returns an error if the result overflows `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ethers::types::U256;
+    /// use fixedpointmath::{fixed_u256, FixedPoint, RoundingMode};
+    ///
+    /// let amount = fixed_u256!(1.0000005e18);
+    /// let down: FixedPoint<U256, 6> = amount.rescale(RoundingMode::Floor).unwrap();
+    /// let up: FixedPoint<U256, 6> = amount.rescale(RoundingMode::Ceil).unwrap();
+    /// assert_eq!(down.raw(), U256::from(1_000_000));
+    /// assert_eq!(up.raw(), U256::from(1_000_001));
+    /// ```
+    pub fn rescale<const D2: u8>(self, rounding: RoundingMode) -> Result<FixedPoint<T, D2>> {
+        let overflow = || FixedPointError::overflow(Operation::Rescale(D2), &[&self]);
+        let abs = Wide::<T>::from(self.raw().unsigned_abs());
+        let abs = match D2.cmp(&D) {
+            Ordering::Less => {
+                let scale = Wide::<T>::exp10((D - D2).into());
+                let (quotient, remainder) = abs.div_mod(scale.clone());
+                rounding.round(self.sign(), quotient, remainder, scale)
+            }
+            _ => abs
+                .checked_mul(Wide::<T>::exp10((D2 - D).into()))
+                .ok_or_else(overflow)?,
+        };
+        T::Unsigned::try_from_wide(abs)
+            .and_then(|abs| FixedPoint::from_sign_and_abs(self.sign(), abs).ok())
+            .ok_or_else(overflow)
     }
 
     // Conversion to other FixedPoint types //
 
     /// Creates a `FixedPoint` instance with the same value as this instance but
-    /// with a different underlying `FixedPointValue` type.
+    /// with a different underlying `FixedPointValue` type. Returns an error if
+    /// the value doesn't fit in the new type.
     ///
     /// # Example
     ///
@@ -147,109 +310,417 @@ impl<T: FixedPointValue> FixedPoint<T> {
     /// let fp_u128: FixedPoint<u128> = fp_i128.change_type()?;
     /// assert_eq!(fp_u128, fixed_u128!(1));
     /// ```
-    pub fn change_type<U: FixedPointValue + TryFrom<T>>(self) -> Result<FixedPoint<U>> {
-        self.raw().try_to_fixed()
+    pub fn change_type<U: FixedPointValue + TryFrom<T>>(self) -> Result<FixedPoint<U, D>> {
+        FixedPoint::<U, D>::try_from(self.raw())
     }
 
-    // Conversion to unsigned & signed ethers types //
+    // Conversion to unsigned & signed wide types //
 
     pub fn to_u256(self) -> Result<U256> {
         if self.is_negative() {
-            bail!("Cannot convert negative FixedPoint {self} to U256.");
+            return Err(FixedPointError::NegativeToUnsigned {
+                value: self.to_string(),
+                target: "U256",
+            });
         }
         self.raw().to_u256()
     }
 
+    pub fn to_u512(self) -> Result<U512> {
+        if self.is_negative() {
+            return Err(FixedPointError::NegativeToUnsigned {
+                value: self.to_string(),
+                target: "U512",
+            });
+        }
+        self.raw().to_u512()
+    }
+
+    #[cfg(feature = "ethers")]
     pub fn to_i256(self) -> Result<I256> {
-        let abs = self.unsigned_abs().raw();
+        let out_of_range = || FixedPointError::OutOfRange {
+            value: self.to_string(),
+            target: "I256",
+        };
+        let abs = self.unsigned_abs().to_u256().map_err(|_| out_of_range())?;
         let abs_max = FixedPoint::<I256>::saturate_sign(self.sign())
             .raw()
             .unsigned_abs();
         if abs > abs_max {
-            bail!("FixedPoint {self} is too large to convert to I256.");
+            return Err(out_of_range());
         }
-        I256::checked_from_sign_and_abs(self.sign().into(), abs)
-            .ok_or(eyre!("Failed to convert FixedPoint {self} to I256."))
+        I256::checked_from_sign_and_abs(self.sign().into(), abs).ok_or_else(out_of_range)
     }
 
     // Conversion to unsigned and signed std types //
 
     pub fn to_u128(self) -> Result<u128> {
         if self.is_negative() {
-            bail!("Cannot convert negative FixedPoint {self} to u128.");
+            return Err(FixedPointError::NegativeToUnsigned {
+                value: self.to_string(),
+                target: "u128",
+            });
         }
         self.raw().to_u128()
     }
 
     pub fn to_i128(self) -> Result<i128> {
-        let i256 = self.to_i256()?;
-        i128::try_from(i256)
-            .or_else(|_| bail!("FixedPoint {self} is too large to convert to i128."))
+        let out_of_range = |_| FixedPointError::OutOfRange {
+            value: self.to_string(),
+            target: "i128",
+        };
+        self.unsigned_abs()
+            .to_u128()
+            .and_then(|abs| FixedPoint::<i128>::from_sign_and_abs(self.sign(), abs))
+            .map(|fixed| fixed.raw())
+            .map_err(out_of_range)
     }
 
-    // Formatting //
+    // Conversion to and from floats //
 
-    pub fn to_scaled_string(&self) -> String {
-        let decimals = (self.decimals()) as usize;
-        let zero = U256::from(0);
-        let ten = U256::from(10);
-        let char_code_zero = U256::from(48);
-        let mut value = self.raw().unsigned_abs();
-        let mut digits = 0;
-        let mut result = vec![];
-        while value > zero {
-            if digits == decimals && decimals > 0 {
-                result.push('.');
+    /// Converts an `f64` into a `FixedPoint` with `D` decimals, rounding with
+    /// the given mode if it has more decimal places. The conversion is exact,
+    /// so, e.g., `0.1` is `0.1000000000000000055511151231257827...` before
+    /// rounding. Converting the result back with [`FixedPoint::to_f64`] gives
+    /// the original value unless it's too small for the decimals to tell it
+    /// apart from its neighbors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ethers::types::I256;
+    /// use fixedpointmath::{fixed, FixedPoint, RoundingMode};
+    ///
+    /// let value = FixedPoint::<I256>::from_f64(-1.25, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(value, fixed!(-1.25e18));
+    ///
+    /// let value = FixedPoint::<I256>::from_f64(-0.1, RoundingMode::TowardZero).unwrap();
+    /// assert_eq!(value.to_string(), "-0.100000000000000005");
+    /// let value = FixedPoint::<I256>::from_f64(-0.1, RoundingMode::HalfEven).unwrap();
+    /// assert_eq!(value.to_string(), "-0.100000000000000006");
+    /// assert_eq!(value.to_f64(), -0.1);
+    /// assert!(FixedPoint::<I256>::from_f64(f64::NAN, RoundingMode::HalfEven).is_err());
+    /// ```
+    pub fn from_f64(value: f64, rounding: RoundingMode) -> Result<Self> {
+        if !value.is_finite() {
+            return Err(FixedPointError::NonFinite {
+                value: value.to_string(),
+            });
+        }
+        let out_of_range = || FixedPointError::OutOfRange {
+            value: value.to_string(),
+            target: core::any::type_name::<T>(),
+        };
+        let sign = match value.is_sign_negative() {
+            true => FixedPointSign::Negative,
+            false => FixedPointSign::Positive,
+        };
+
+        // Decompose the absolute value into `mantissa * 2^exponent`.
+        let bits = value.to_bits();
+        let (mantissa, exponent) = match (bits >> 52) & 0x7ff {
+            0 => (bits & FRACTION_MASK, -1074),
+            biased => (bits & FRACTION_MASK | 1 << 52, biased as i64 - 1075),
+        };
+
+        let abs = Extended::<T>::from_u64(mantissa)
+            .checked_mul(Extended::<T>::exp10(D.into()))
+            .ok_or_else(out_of_range)?;
+        let abs = if exponent >= 0 {
+            abs.checked_mul(Extended::<T>::pow2(exponent as usize))
+                .ok_or_else(out_of_range)?
+        } else {
+            // A divisor with more than 1 bit past the value leaves a remainder
+            // below half that rounds the same as any other, so the shift is
+            // capped to keep the divisor in range.
+            let shift = (exponent.unsigned_abs() as usize).min(abs.bits() + 1);
+            let divisor = Extended::<T>::pow2(shift);
+            let (quotient, remainder) = abs.div_mod(divisor.clone());
+            rounding.round(sign, quotient, remainder, divisor)
+        };
+        let abs = T::Unsigned::try_from_extended(abs).ok_or_else(out_of_range)?;
+        Self::from_sign_and_abs(sign, abs).map_err(|err| match err {
+            FixedPointError::OutOfRange { .. } => out_of_range(),
+            err => err,
+        })
+    }
+
+    /// Converts the number into the nearest `f64`, rounding ties to even.
+    /// Values beyond the range of `f64`, which only unbounded types can hold,
+    /// are converted to infinity.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// assert_eq!(fixed_i256!(-1.5e18).to_f64(), -1.5);
+    /// assert_eq!(fixed_i256!(0.1e18).to_f64(), 0.1);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        let abs = Extended::<T>::from(self.raw().unsigned_abs());
+        if abs.is_zero() {
+            return 0.0;
+        }
+        let scale = Extended::<T>::exp10(self.decimals().into());
+
+        // Find the shift that puts the quotient `abs * 2^shift / scale` in
+        // `[2^52, 2^53)`, i.e., the 53 bits of an `f64` mantissa. Starting
+        // from the difference in bit lengths leaves the quotient with 53 or
+        // 54 bits.
+        let mut shift = 53 + scale.bits() as i64 - abs.bits() as i64;
+        let (quotient, remainder, divisor) = loop {
+            let (numerator, divisor) = match shift {
+                0.. => (
+                    abs.clone() * Extended::<T>::pow2(shift as usize),
+                    scale.clone(),
+                ),
+                _ => (
+                    abs.clone(),
+                    scale.clone() * Extended::<T>::pow2(shift.unsigned_abs() as usize),
+                ),
+            };
+            let (quotient, remainder) = numerator.div_mod(divisor.clone());
+            if quotient.bits() <= 53 {
+                break (quotient, remainder, divisor);
             }
+            shift -= 1;
+        };
+        let mantissa = RoundingMode::HalfEven
+            .round(FixedPointSign::Positive, quotient, remainder, divisor)
+            .low_u64();
+        let (mantissa, shift) = match mantissa {
+            MANTISSA_CARRY => (MANTISSA_CARRY >> 1, shift - 1),
+            mantissa => (mantissa, shift),
+        };
 
-            let char_code = (value % ten + char_code_zero).to_u128().unwrap();
-            result.push(char_code as u8 as char);
-            value /= ten;
-            digits += 1;
+        // The smallest non-zero value, `10^-255`, is a normal `f64`, so the
+        // biased exponent is always positive.
+        let biased = 1075 - shift;
+        let abs = match biased {
+            2047.. => f64::INFINITY,
+            _ => f64::from_bits((biased as u64) << 52 | mantissa & FRACTION_MASK),
+        };
+        match self.is_negative() {
+            true => -abs,
+            false => abs,
         }
+    }
+
+    // Formatting //
+
+    pub fn to_scaled_string(&self) -> String {
+        format!("{}{}", self.sign(), self.fmt_abs(self.decimals().into()))
+    }
 
-        // Add leading zeros.
-        if digits < decimals {
-            result.resize(result.len() + decimals - digits, '0');
-            digits += decimals - digits;
+    /// Formats the absolute value with the given number of decimal places,
+    /// rounding half to even if it has more.
+    fn fmt_abs(&self, places: usize) -> String {
+        let decimals = usize::from(self.decimals());
+        let mut abs = Wide::<T>::from(self.raw().unsigned_abs());
+        if places < decimals {
+            let scale = Wide::<T>::exp10(decimals - places);
+            let (quotient, remainder) = abs.div_mod(scale.clone());
+            abs = RoundingMode::HalfEven.round(self.sign(), quotient, remainder, scale);
         }
 
-        // Add the decimal point and leading zero.
-        if digits == decimals {
-            if decimals > 0 {
-                result.push('.');
-            }
-            result.push('0');
+        // Pad the digits so there's at least one integer digit.
+        let kept = places.min(decimals);
+        let digits = format!("{:0>width$}", abs.to_string(), width = kept + 1);
+        let (int, fraction) = digits.split_at(digits.len() - kept);
+        if places == 0 {
+            return int.to_string();
         }
+        format!("{int}.{fraction:0<places$}")
+    }
 
-        format!("{}{}", self.sign(), result.iter().rev().collect::<String>())
+    /// Formats the value in scientific notation with the given exponent
+    /// character.
+    fn fmt_exp(&self, f: &mut fmt::Formatter<'_>, e: char) -> fmt::Result {
+        let abs = Wide::<T>::from(self.raw().unsigned_abs());
+        let digits = abs.to_string();
+        let mut exponent = match abs.is_zero() {
+            true => 0,
+            false => digits.len() as i64 - 1 - i64::from(self.decimals()),
+        };
+        let mantissa = match f.precision() {
+            Some(precision) if digits.len() > precision + 1 => {
+                let scale = Wide::<T>::exp10(digits.len() - precision - 1);
+                let (quotient, remainder) = abs.div_mod(scale.clone());
+                let mut rounded = RoundingMode::HalfEven
+                    .round(self.sign(), quotient, remainder, scale)
+                    .to_string();
+                // Rounding up 9s carries into a new leading digit.
+                if rounded.len() > precision + 1 {
+                    rounded.truncate(precision + 1);
+                    exponent += 1;
+                }
+                rounded
+            }
+            Some(precision) => format!("{digits:0<width$}", width = precision + 1),
+            None => match digits.trim_end_matches('0') {
+                "" => "0".to_string(),
+                digits => digits.to_string(),
+            },
+        };
+        let (first, rest) = mantissa.split_at(1);
+        let s = match rest {
+            "" => format!("{first}{e}{exponent}"),
+            rest => format!("{first}.{rest}{e}{exponent}"),
+        };
+        f.pad_integral(!self.is_negative(), "", &s)
     }
 }
 
 // Trait implementations //
 
-impl<T: FixedPointValue> Default for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> Default for FixedPoint<T, D> {
     fn default() -> Self {
         Self::new(T::default())
     }
 }
 
-impl<T: FixedPointValue> fmt::Debug for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> fmt::Debug for FixedPoint<T, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "FixedPoint({})", self.to_scaled_string())
     }
 }
 
-impl<T: FixedPointValue> fmt::Display for FixedPoint<T> {
+/// Formats the scaled value with all of its decimal places, e.g.,
+/// `1.500000000000000000`.
+///
+/// - A precision rounds to that many decimal places, rounding half to even like
+///   the float formatting in `std`, e.g., `{:.2}`.
+/// - The alternate flag trims trailing zeros from the fraction, e.g., `{:#}`
+///   formats `1.5` instead of `1.500000000000000000`.
+/// - The width, fill, alignment, `+`, and `0` flags behave as they do for
+///   integers.
+///
+/// # Example
+///
+/// ```
+/// use fixedpointmath::fixed_u256;
+///
+/// let value = fixed_u256!(1234.5678e18);
+/// assert_eq!(format!("{value:.2}"), "1234.57");
+/// assert_eq!(format!("{value:#}"), "1234.5678");
+/// assert_eq!(format!("{value:>+10.1}"), "   +1234.6");
+/// ```
+impl<T: FixedPointValue, const D: u8> fmt::Display for FixedPoint<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let places = f.precision().unwrap_or(self.decimals().into());
+        let abs = self.fmt_abs(places);
+        let abs = match f.alternate() && abs.contains('.') {
+            true => abs.trim_end_matches('0').trim_end_matches('.'),
+            false => &abs,
+        };
+        f.pad_integral(!self.is_negative(), "", abs)
+    }
+}
+
+/// Implements the radix formatting traits for the raw value of a `FixedPoint`.
+/// Negative values are formatted as their sign and absolute value, e.g.,
+/// `-0xff`, rather than as two's complement.
+macro_rules! fmt_radix_impls {
+    ($($trait:ident, $prefix:literal, $limb_fmt:literal);* $(;)?) => {
+        $(
+            impl<T: FixedPointValue, const D: u8> fmt::$trait for FixedPoint<T, D> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    let digits = self
+                        .raw()
+                        .unsigned_abs()
+                        .into_limbs()
+                        .iter()
+                        .rev()
+                        .map(|limb| format!($limb_fmt, limb))
+                        .collect::<String>();
+                    let digits = match digits.trim_start_matches('0') {
+                        "" => "0",
+                        digits => digits,
+                    };
+                    f.pad_integral(!self.is_negative(), $prefix, digits)
+                }
+            }
+        )*
+    };
+}
+
+fmt_radix_impls!(
+    LowerHex, "0x", "{:016x}";
+    UpperHex, "0x", "{:016X}";
+    Binary, "0b", "{:064b}";
+);
+
+/// Formats the scaled value in scientific notation, e.g., `1.5e3` for `1500`.
+/// A precision rounds the mantissa to that many decimal places, rounding half
+/// to even.
+impl<T: FixedPointValue, const D: u8> fmt::LowerExp for FixedPoint<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_exp(f, 'e')
+    }
+}
+
+/// Formats the scaled value in scientific notation, e.g., `1.5E3` for `1500`.
+impl<T: FixedPointValue, const D: u8> fmt::UpperExp for FixedPoint<T, D> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.to_scaled_string())
+        self.fmt_exp(f, 'E')
+    }
+}
+
+/// Parses the scaled value of a fixed point number, e.g., `"1.5"` or
+/// `"-2.5e3"`, which round trips with `Display`.
+///
+/// The string may have a sign, `_` separators between digits, a fraction, and
+/// an exponent. Integers may instead be written in hexadecimal, binary, or
+/// octal with a `0x`, `0b`, or `0o` prefix. Strings with more decimal places
+/// than `D` or outside of the range of `T` are rejected, and parse errors
+/// report the byte offset where parsing failed.
+///
+/// # Example
+///
+/// ```
+/// use ethers::types::U256;
+/// use fixedpointmath::{fixed, FixedPoint, FixedPointError};
+///
+/// assert_eq!("1_000.5".parse::<FixedPoint<U256>>(), Ok(fixed!(1_000.5e18)));
+/// assert_eq!("0x10".parse::<FixedPoint<U256>>(), Ok(fixed!(16e18)));
+/// assert!(matches!(
+///     "1-2".parse::<FixedPoint<U256>>(),
+///     Err(FixedPointError::Parse { position: 1, .. })
+/// ));
+/// ```
+impl<T: FixedPointValue, const D: u8> FromStr for FixedPoint<T, D> {
+    type Err = FixedPointError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse::<T>(s, D.into()).map(Self::new)
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> PartialEq for FixedPoint<T, D> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> Eq for FixedPoint<T, D> {}
+
+impl<T: FixedPointValue, const D: u8> PartialOrd for FixedPoint<T, D> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> Ord for FixedPoint<T, D> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.raw.cmp(&other.raw)
     }
 }
 
 // Conversions //
 
 // Basic raw to FixedPoint conversion.
-impl<T: FixedPointValue> From<T> for FixedPoint<T> {
+impl<T: FixedPointValue, const D: u8> From<T> for FixedPoint<T, D> {
     fn from(value: T) -> Self {
         Self::new(value)
     }
@@ -327,21 +798,19 @@ impl<T: Sized + Debug> ToFixed for T {}
 macro_rules! conversion_impls {
     ($($t:ty),*) => {
         $(
-            impl<T: FixedPointValue + From<$t>> From<$t> for FixedPoint<T> {
+            impl<T: FixedPointValue + From<$t>, const D: u8> From<$t> for FixedPoint<T, D> {
                 fn from(u: $t) -> Self {
                     Self::new(u)
                 }
             }
 
-            impl<T: FixedPointValue + TryInto<$t>> TryFrom<FixedPoint<T>> for $t {
-                type Error = eyre::ErrReport;
+            impl<T: FixedPointValue + TryInto<$t>, const D: u8> TryFrom<FixedPoint<T, D>> for $t {
+                type Error = FixedPointError;
 
-                fn try_from(f: FixedPoint<T>) -> eyre::Result<Self> {
-                    f.raw().try_into().map_err(|_| {
-                        eyre!(
-                            "Failed to convert underlying FixedPointValue to {type}: {f:?}",
-                            type = stringify!($t)
-                        )
+                fn try_from(f: FixedPoint<T, D>) -> Result<Self> {
+                    f.raw().try_into().map_err(|_| FixedPointError::OutOfRange {
+                        value: format!("{f:?}"),
+                        target: stringify!($t),
                     })
                 }
             }
@@ -351,12 +820,15 @@ macro_rules! conversion_impls {
 }
 
 // Direct conversions between primitive types and FixedPoint for any
-// `FixedPointValue` that can be converted to and from the primitive type.
-conversion_impls!(i8, u8, i16, u16, i32, u32, i64, u64, isize, usize, [u8; 32], bool);
+// `FixedPointValue` that can be converted to and from the primitive type. The
+// primitive types that implement `FixedPointValue` themselves get their
+// conversions from `fixed_point_value_impl!` instead.
+conversion_impls!(i8, u8, i16, u16, i32, isize, usize, [u8; 32], bool);
 
 #[cfg(test)]
 mod tests {
-    use crate::fixed_i128;
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256, int256};
 
     #[test]
     fn test_change_type_failure() {
@@ -365,6 +837,296 @@ mod tests {
         assert!(fixed_u128.is_err());
     }
 
+    #[test]
+    fn test_with_decimals() -> Result<()> {
+        let usdc = FixedPoint::<U256, 6>::with_decimals(1_500_000_u128, 6)?;
+        assert_eq!(usdc, FixedPoint::new(1_500_000_u128));
+        assert_eq!(usdc.to_string(), "1.500000");
+
+        let ray = FixedPoint::<U256, 27>::with_decimals(U256::exp10(27), 27)?;
+        assert_eq!(ray.one(), ray);
+        assert_eq!(ray.to_string(), format!("1.{}", "0".repeat(27)));
+
+        // The scale must be the type's decimals.
+        assert_eq!(
+            FixedPoint::<U256, 6>::with_decimals(1_u128, 18),
+            Err(FixedPointError::InvalidDecimals {
+                decimals: 18,
+                expected: 6
+            })
+        );
+        assert_eq!(
+            FixedPoint::<u128>::with_decimals(1_u128, 6),
+            Err(FixedPointError::InvalidDecimals {
+                decimals: 6,
+                expected: 18
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_const_decimals() {
+        let usdc = FixedPoint::<U256, 6>::new(1_500_000_u128);
+        assert_eq!(usdc.decimals(), 6);
+        assert_eq!(usdc.one().raw(), U256::from(1_000_000));
+        assert_eq!(usdc.to_string(), "1.500000");
+        assert_eq!(FixedPoint::<U256, 6>::MAX.decimals(), 6);
+        assert_eq!(
+            FixedPoint::<i128, 0>::MIN.to_string(),
+            i128::MIN.to_string()
+        );
+
+        // The macros infer the scale from the context.
+        let fee: FixedPoint<U256, 6> = fixed!(0.003e6);
+        assert_eq!(usdc.mul_up(fee).raw(), U256::from(4_500));
+        assert_eq!(usdc.mul_down(fee).to_string(), "0.004500");
+        assert_eq!(
+            (usdc - fee).div_down(fixed!(2e6)),
+            FixedPoint::new(748_500_u128)
+        );
+    }
+
+    #[test]
+    fn test_rescale() -> Result<()> {
+        let amount = fixed_i256!(1.0000005e18);
+        let down: FixedPoint<I256, 6> = amount.rescale(RoundingMode::TowardZero)?;
+        let up: FixedPoint<I256, 6> = amount.rescale(RoundingMode::AwayFromZero)?;
+        assert_eq!(down.raw(), I256::from(1_000_000));
+        assert_eq!(down.decimals(), 6);
+        assert_eq!(up.raw(), I256::from(1_000_001));
+
+        // Directed rounding depends on the sign.
+        let down: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::TowardZero)?;
+        let up: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::AwayFromZero)?;
+        let floor: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::Floor)?;
+        let ceil: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::Ceil)?;
+        assert_eq!(down.raw(), I256::from(-1_000_000));
+        assert_eq!(up.raw(), I256::from(-1_000_001));
+        assert_eq!(floor.raw(), I256::from(-1_000_001));
+        assert_eq!(ceil.raw(), I256::from(-1_000_000));
+
+        // Ties round away from zero, towards zero, to the even neighbor, or
+        // in a fixed direction.
+        let half_up: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::HalfUp)?;
+        let half_down: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::HalfDown)?;
+        let half_even: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::HalfEven)?;
+        let half_ceil: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::HalfCeil)?;
+        let half_floor: FixedPoint<I256, 6> = (-amount).rescale(RoundingMode::HalfFloor)?;
+        assert_eq!(half_up.raw(), I256::from(-1_000_001));
+        assert_eq!(half_down.raw(), I256::from(-1_000_000));
+        assert_eq!(half_even.raw(), I256::from(-1_000_000));
+        assert_eq!(half_ceil.raw(), I256::from(-1_000_000));
+        assert_eq!(half_floor.raw(), I256::from(-1_000_001));
+        let half_ceil: FixedPoint<I256, 6> = amount.rescale(RoundingMode::HalfCeil)?;
+        let half_floor: FixedPoint<I256, 6> = amount.rescale(RoundingMode::HalfFloor)?;
+        assert_eq!(half_ceil.raw(), I256::from(1_000_001));
+        assert_eq!(half_floor.raw(), I256::from(1_000_000));
+
+        // Exact conversions and increasing the scale don't round.
+        let exact: FixedPoint<I256, 6> = fixed_i256!(1.5e18).rescale(RoundingMode::AwayFromZero)?;
+        assert_eq!(exact.raw(), I256::from(1_500_000));
+        let ray: FixedPoint<I256, 27> = exact.rescale(RoundingMode::TowardZero)?;
+        assert_eq!(ray.raw(), int256!(1.5e27));
+        assert_eq!(ray, exact.rescale::<27>(RoundingMode::AwayFromZero)?);
+
+        // Overflows are errors.
+        assert!(FixedPoint::<u128>::MAX
+            .rescale::<19>(RoundingMode::TowardZero)
+            .is_err());
+        assert!(FixedPoint::<u128, 0>::MAX
+            .rescale::<18>(RoundingMode::AwayFromZero)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conversion_errors() {
+        assert!(matches!(
+            fixed_i256!(-1e18).to_u256(),
+            Err(FixedPointError::NegativeToUnsigned { target: "U256", .. })
+        ));
+        assert!(matches!(
+            fixed_i128!(-1e18).to_u128(),
+            Err(FixedPointError::NegativeToUnsigned { target: "u128", .. })
+        ));
+        assert!(matches!(
+            FixedPoint::<U256>::MAX.to_i256(),
+            Err(FixedPointError::OutOfRange { target: "I256", .. })
+        ));
+        assert!(matches!(
+            FixedPoint::<I256>::MAX.to_i128(),
+            Err(FixedPointError::OutOfRange { target: "i128", .. })
+        ));
+        assert!(matches!(
+            u8::try_from(fixed_u256!(256)),
+            Err(FixedPointError::OutOfRange { target: "u8", .. })
+        ));
+        assert_eq!(
+            fixed_u128!(1e18).change_decimals(39),
+            Err(FixedPointError::InvalidDecimals {
+                decimals: 39,
+                expected: 18
+            })
+        );
+        assert!(matches!(
+            FixedPoint::<U256>::from_dec_str("1.5x"),
+            Err(FixedPointError::Parse { .. })
+        ));
+    }
+
+    #[test]
+    fn test_change_decimals() -> Result<()> {
+        // The decimals of a value are always the type's decimals.
+        let usdc = FixedPoint::<I256, 6>::new(-1_500_000);
+        assert_eq!(usdc.change_decimals(6)?, usdc);
+        assert_eq!(
+            usdc.change_decimals(18),
+            Err(FixedPointError::InvalidDecimals {
+                decimals: 18,
+                expected: 6
+            })
+        );
+        assert!(fixed_i256!(1e18).change_decimals(6).is_err());
+        assert!(fixed_u128!(1e18).change_decimals(39).is_err());
+
+        // No operation changes the decimals of a value.
+        let rate = FixedPoint::<I256, 6>::new(1_050_000);
+        for value in [
+            usdc,
+            usdc + rate,
+            usdc.mul_down(rate),
+            usdc.div_up(rate),
+            rate.pow(rate)?,
+            rate.ln()?,
+            rate.exp()?,
+            rate.sqrt_up()?,
+            usdc.abs(),
+            usdc.trunc(),
+            usdc.checked_quantize(rate, RoundingMode::HalfUp)?,
+            "1.5".parse()?,
+            fixed!(1.5e6),
+            FixedPoint::MIN,
+            FixedPoint::from_f64(0.1, RoundingMode::HalfEven)?,
+            fixed_i256!(1.5e18).rescale(RoundingMode::Floor)?,
+        ] {
+            assert_eq!(value.decimals(), 6);
+            assert_eq!(value.one().raw(), I256::from(1_000_000));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_f64() -> Result<()> {
+        // Values are rounded to the nearest `f64`.
+        assert_eq!(fixed_i256!(0).to_f64(), 0.0);
+        assert_eq!(fixed_i256!(-2.5e18).to_f64(), -2.5);
+        assert_eq!(fixed_i256!(0.3e18).to_f64(), 0.3);
+        assert_eq!(fixed_u256!(1).to_f64(), 1e-18);
+        assert_eq!(FixedPoint::<I256>::MIN.to_f64(), -5.78960446186581e58);
+        assert_eq!(FixedPoint::<U512>::MAX.to_f64(), 1.3407807929942597e136);
+        assert_eq!(FixedPoint::<u128, 38>::new(1_u128).to_f64(), 1e-38);
+        // 2^53 + 1 is a tie between 2^53 and 2^53 + 2, which rounds to even.
+        assert_eq!(
+            FixedPoint::<u128, 0>::new(9_007_199_254_740_993_u128).to_f64(),
+            9_007_199_254_740_992.0
+        );
+        assert_eq!(
+            FixedPoint::<u128, 0>::new(9_007_199_254_740_995_u128).to_f64(),
+            9_007_199_254_740_996.0
+        );
+
+        // Floats are converted exactly before rounding to the decimals.
+        let from_f64 = |value: f64, rounding| FixedPoint::<I256>::from_f64(value, rounding);
+        assert_eq!(from_f64(1.5, RoundingMode::Floor)?, fixed!(1.5e18));
+        assert_eq!(
+            from_f64(0.1, RoundingMode::HalfEven)?,
+            "0.100000000000000006".parse()?
+        );
+        assert_eq!(
+            from_f64(0.1, RoundingMode::TowardZero)?,
+            "0.100000000000000005".parse()?
+        );
+        assert_eq!(
+            from_f64(-0.1, RoundingMode::Ceil)?,
+            "-0.100000000000000005".parse()?
+        );
+        assert_eq!(from_f64(1e40, RoundingMode::Floor)?.to_f64(), 1e40);
+        assert_eq!(from_f64(-0.0, RoundingMode::Floor)?, fixed!(0));
+        assert_eq!(from_f64(5e-324, RoundingMode::HalfUp)?, fixed!(0));
+        assert_eq!(from_f64(5e-324, RoundingMode::Ceil)?, fixed!(1));
+        assert_eq!(from_f64(-5e-324, RoundingMode::Floor)?, fixed!(-1));
+        assert_eq!(
+            FixedPoint::<u32, 9>::from_f64(4.294967295, RoundingMode::HalfEven)?,
+            FixedPoint::MAX
+        );
+        assert_eq!(
+            FixedPoint::<U256, 0>::from_f64(-0.4, RoundingMode::HalfEven)?,
+            FixedPoint::new(0_u128)
+        );
+
+        // Non-finite and out of range values are errors.
+        assert_eq!(
+            from_f64(f64::NEG_INFINITY, RoundingMode::HalfEven),
+            Err(FixedPointError::NonFinite {
+                value: "-inf".into()
+            })
+        );
+        assert!(matches!(
+            from_f64(f64::NAN, RoundingMode::HalfEven),
+            Err(FixedPointError::NonFinite { .. })
+        ));
+        assert!(matches!(
+            from_f64(-1e59, RoundingMode::HalfEven),
+            Err(FixedPointError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            from_f64(f64::MAX, RoundingMode::HalfEven),
+            Err(FixedPointError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            FixedPoint::<u32, 9>::from_f64(4.3, RoundingMode::HalfEven),
+            Err(FixedPointError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            FixedPoint::<U256>::from_f64(-1.0, RoundingMode::HalfEven),
+            Err(FixedPointError::NegativeToUnsigned { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzz_f64() -> Result<()> {
+        use rand::{thread_rng, Rng};
+
+        // The standard library's float parsing and formatting are correctly
+        // rounded, so they're exact references for the conversions.
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let value: FixedPoint<I256> = rng.gen();
+            assert_eq!(value.to_f64(), value.to_string().parse::<f64>().unwrap());
+            let value: FixedPoint<u128, 30> = rng.gen();
+            assert_eq!(value.to_f64(), value.to_string().parse::<f64>().unwrap());
+
+            let float = f64::from_bits(rng.gen()) % 1e40;
+            if float.is_finite() {
+                let value = FixedPoint::<I256>::from_f64(float, RoundingMode::HalfEven)?;
+                assert_eq!(value, format!("{float:.18}").parse()?, "{float:e}");
+                // Floats with enough integer digits round-trip through the
+                // decimals.
+                if float.abs() >= 0.01 {
+                    assert_eq!(value.to_f64(), float, "{float:e}");
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn test_fmt() {
         // fmt::Debug
@@ -399,5 +1161,63 @@ mod tests {
             format!("{}", fixed_i128!(-50_000.234_56e18)),
             "-50000.234560000000000000"
         );
+        assert_eq!(format!("{}", FixedPoint::<U256, 0>::new(5_u128)), "5");
+
+        // Precision rounds half to even.
+        assert_eq!(format!("{:.4}", fixed_i128!(1.23456e18)), "1.2346");
+        assert_eq!(format!("{:.2}", fixed_i128!(1.125e18)), "1.12");
+        assert_eq!(format!("{:.2}", fixed_i128!(-1.135e18)), "-1.14");
+        assert_eq!(format!("{:.1}", fixed_i128!(9.96e18)), "10.0");
+        assert_eq!(format!("{:.0}", fixed_i128!(2.5e18)), "2");
+        assert_eq!(format!("{:.3}", fixed_i128!(-0.0001e18)), "-0.000");
+        assert_eq!(
+            format!("{:.20}", fixed_i128!(1.5e18)),
+            "1.50000000000000000000"
+        );
+        let usdc = FixedPoint::<U256, 6>::new(1_500_000_u128);
+        assert_eq!(format!("{usdc:.8}"), "1.50000000");
+
+        // Width, fill, alignment, sign, and trimming.
+        assert_eq!(
+            format!("{:+}", fixed_i128!(1.5e18)),
+            "+1.500000000000000000"
+        );
+        assert_eq!(
+            format!("{:#}", fixed_i128!(-50_000.234_56e18)),
+            "-50000.23456"
+        );
+        assert_eq!(format!("{:#}", fixed_i128!(2e18)), "2");
+        assert_eq!(format!("{:#.2}", fixed_i128!(1.999e18)), "2");
+        assert_eq!(format!("{:8.2}", fixed_i128!(1.5e18)), "    1.50");
+        assert_eq!(format!("{:<8.2}|", fixed_i128!(-1.5e18)), "-1.50   |");
+        assert_eq!(format!("{:*^9.1}", fixed_i128!(1.5e18)), "***1.5***");
+        assert_eq!(format!("{:+08.2}", fixed_i128!(1.5e18)), "+0001.50");
+        assert_eq!(format!("{:08.2}", fixed_i128!(-1.5e18)), "-0001.50");
+
+        // fmt::LowerHex, fmt::UpperHex, and fmt::Binary
+        assert_eq!(format!("{:x}", fixed_i256!(255)), "ff");
+        assert_eq!(format!("{:#X}", fixed_i256!(-255)), "-0xFF");
+        assert_eq!(format!("{:#b}", fixed_i128!(5)), "0b101");
+        assert_eq!(format!("{:#010x}", fixed_u256!(255)), "0x000000ff");
+        assert_eq!(format!("{:x}", fixed_u256!(0)), "0");
+        assert_eq!(
+            format!("{:x}", FixedPoint::<U256>::MAX),
+            format!("{:x}", U256::MAX)
+        );
+        assert_eq!(
+            format!("{:x}", FixedPoint::<I256>::MIN),
+            format!("-8{}", "0".repeat(63))
+        );
+
+        // fmt::LowerExp and fmt::UpperExp
+        assert_eq!(format!("{:e}", fixed_i128!(1_500e18)), "1.5e3");
+        assert_eq!(format!("{:E}", fixed_i128!(-0.0012e18)), "-1.2E-3");
+        assert_eq!(format!("{:e}", fixed_i128!(1)), "1e-18");
+        assert_eq!(format!("{:e}", fixed_i128!(0)), "0e0");
+        assert_eq!(format!("{:.2e}", fixed_i128!(0)), "0.00e0");
+        assert_eq!(format!("{:.2e}", fixed_i128!(1_234.5e18)), "1.23e3");
+        assert_eq!(format!("{:.1e}", fixed_i128!(9.96e18)), "1.0e1");
+        assert_eq!(format!("{:.3e}", fixed_i128!(2e18)), "2.000e0");
+        assert_eq!(format!("{:+10.1e}", fixed_i128!(2e18)), "    +2.0e0");
     }
 }
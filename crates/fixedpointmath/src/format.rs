@@ -0,0 +1,1051 @@
+//! Locale-aware formatting of `FixedPoint` numbers for display, e.g., in
+//! reports, without JavaScript's `Intl.NumberFormat`.
+//!
+//! The options mirror the `FormatOptions` and `CurrencyFormatOptions` of the
+//! `fixedpointwasm` crate, which uses this module as a fallback when `Intl`
+//! isn't available. The built-in [`Locale`]s approximate the output of `Intl`
+//! for their locales, while currency symbols and names are the same in every
+//! locale. Formatted numbers can be parsed back with
+//! [`FixedPoint::parse_formatted`].
+//!
+//! # Example
+//!
+//! ```
+//! use fixedpointmath::{
+//!     fixed_u256,
+//!     format::{CompactDisplay, CurrencyFormatOptions, FormatOptions, Locale},
+//! };
+//!
+//! let tvl = fixed_u256!(1_234_567.891e18);
+//! assert_eq!(tvl.format(&FormatOptions::default()), "1,234,567.891");
+//!
+//! let mut options = FormatOptions::default();
+//! options.compact_display = Some(CompactDisplay::Short);
+//! assert_eq!(tvl.format(&options), "1.2M");
+//!
+//! let mut options = CurrencyFormatOptions::default();
+//! options.base.locale = Locale::DE_DE;
+//! options.currency = "EUR".into();
+//! assert_eq!(tvl.format_currency(&options), "1.234.567,89\u{a0}€");
+//! ```
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::cmp::Reverse;
+
+use crate::{
+    parse::parse,
+    wide::{Wide, WideValue},
+    FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, Result, RoundingMode,
+};
+
+// Locales //
+
+/// The separators and words used to format numbers in a locale.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// The BCP 47 tag of the locale, e.g., `"en-US"`.
+    pub tag: &'static str,
+    /// The decimal mark.
+    pub decimal: char,
+    /// The group separator.
+    pub group: char,
+    /// The number of digits in the group closest to the decimal mark, or `0`
+    /// to not group digits.
+    pub primary_group: usize,
+    /// The number of digits in the remaining groups, e.g., `2` for the lakh
+    /// and crore groups used in India, or `0` to only group the digits closest
+    /// to the decimal mark.
+    pub secondary_group: usize,
+    /// The text between a number and the percent sign.
+    pub percent_separator: &'static str,
+    /// Whether currency symbols go after the number instead of before it.
+    pub currency_after: bool,
+    /// The text between a number and its currency symbol.
+    pub currency_separator: &'static str,
+    /// The units used in compact notation, in any order.
+    pub compact_units: &'static [CompactUnit],
+}
+
+/// A unit used to abbreviate large numbers in compact notation, e.g., `K` for
+/// thousands.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompactUnit {
+    /// The power of 10 the unit represents.
+    pub exponent: usize,
+    /// The suffix used with [`CompactDisplay::Short`], e.g., `"K"`.
+    pub short: &'static str,
+    /// The suffix used with [`CompactDisplay::Long`], e.g., `" thousand"`.
+    pub long: &'static str,
+}
+
+impl Locale {
+    pub const EN_US: Locale = Locale {
+        tag: "en-US",
+        decimal: '.',
+        group: ',',
+        primary_group: 3,
+        secondary_group: 3,
+        percent_separator: "",
+        currency_after: false,
+        currency_separator: "",
+        compact_units: &[
+            CompactUnit::new(3, "K", " thousand"),
+            CompactUnit::new(6, "M", " million"),
+            CompactUnit::new(9, "B", " billion"),
+            CompactUnit::new(12, "T", " trillion"),
+        ],
+    };
+
+    pub const EN_IN: Locale = Locale {
+        tag: "en-IN",
+        secondary_group: 2,
+        compact_units: &[
+            CompactUnit::new(3, "K", " thousand"),
+            CompactUnit::new(5, "L", " lakh"),
+            CompactUnit::new(7, "Cr", " crore"),
+        ],
+        ..Locale::EN_US
+    };
+
+    pub const DE_DE: Locale = Locale {
+        tag: "de-DE",
+        decimal: ',',
+        group: '.',
+        primary_group: 3,
+        secondary_group: 3,
+        percent_separator: "\u{a0}",
+        currency_after: true,
+        currency_separator: "\u{a0}",
+        compact_units: &[
+            CompactUnit::new(3, "\u{a0}Tsd.", " Tausend"),
+            CompactUnit::new(6, "\u{a0}Mio.", " Millionen"),
+            CompactUnit::new(9, "\u{a0}Mrd.", " Milliarden"),
+            CompactUnit::new(12, "\u{a0}Bio.", " Billionen"),
+        ],
+    };
+
+    pub const DE_CH: Locale = Locale {
+        tag: "de-CH",
+        decimal: '.',
+        group: '’',
+        percent_separator: "",
+        currency_after: false,
+        ..Locale::DE_DE
+    };
+
+    pub const FR_FR: Locale = Locale {
+        tag: "fr-FR",
+        decimal: ',',
+        group: '\u{202f}',
+        primary_group: 3,
+        secondary_group: 3,
+        percent_separator: "\u{202f}",
+        currency_after: true,
+        currency_separator: "\u{a0}",
+        compact_units: &[
+            CompactUnit::new(3, "\u{a0}k", " mille"),
+            CompactUnit::new(6, "\u{a0}M", " millions"),
+            CompactUnit::new(9, "\u{a0}Md", " milliards"),
+            CompactUnit::new(12, "\u{a0}Bn", " billions"),
+        ],
+    };
+
+    /// The built-in locales.
+    pub const ALL: [Locale; 5] = [
+        Locale::EN_US,
+        Locale::EN_IN,
+        Locale::DE_DE,
+        Locale::DE_CH,
+        Locale::FR_FR,
+    ];
+
+    /// Finds the built-in locale for a BCP 47 tag, falling back to the locale
+    /// for the tag's language, e.g., `"en-GB"` uses `EN_US`.
+    pub fn from_tag(tag: &str) -> Option<Locale> {
+        let tag = tag.replace('_', "-");
+        let language = tag.split('-').next().unwrap_or_default();
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.tag.eq_ignore_ascii_case(&tag))
+            .or_else(|| match language.to_ascii_lowercase().as_str() {
+                "en" => Some(Locale::EN_US),
+                "de" => Some(Locale::DE_DE),
+                "fr" => Some(Locale::FR_FR),
+                _ => None,
+            })
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::EN_US
+    }
+}
+
+impl CompactUnit {
+    pub const fn new(exponent: usize, short: &'static str, long: &'static str) -> Self {
+        Self {
+            exponent,
+            short,
+            long,
+        }
+    }
+}
+
+// Options //
+
+/// The options shared by [`FormatOptions`] and [`CurrencyFormatOptions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BaseFormatOptions {
+    /// The maximum number of decimal places to display. Defaults to the
+    /// number's decimals, `0` for percentages, the currency's minor unit for
+    /// currencies, and 2 significant digits in compact notation.
+    pub decimals: Option<u8>,
+    /// Whether to keep trailing zeros up to `decimals` places.
+    pub trailing_zeros: bool,
+    /// The rounding mode used when dropping decimal places. Defaults to
+    /// `HalfUp`, i.e., `Intl`'s `"halfExpand"`.
+    pub rounding: RoundingMode,
+    pub locale: Locale,
+    /// Whether to use group separators, e.g., `1,000` instead of `1000`.
+    pub group: bool,
+}
+
+impl Default for BaseFormatOptions {
+    fn default() -> Self {
+        Self {
+            decimals: None,
+            trailing_zeros: false,
+            rounding: RoundingMode::HalfUp,
+            locale: Locale::default(),
+            group: true,
+        }
+    }
+}
+
+/// Options for [`FixedPoint::format`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FormatOptions {
+    pub base: BaseFormatOptions,
+    /// The compact notation to use, if any, e.g., `1.2K`.
+    pub compact_display: Option<CompactDisplay>,
+    /// Whether to format the number as a percentage, e.g., `0.1325` as `13%`.
+    pub percent: bool,
+}
+
+/// Options for [`FixedPoint::format_currency`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CurrencyFormatOptions {
+    pub base: BaseFormatOptions,
+    /// The ISO 4217 code of the currency. Defaults to `"USD"`.
+    pub currency: String,
+    pub display: CurrencyDisplay,
+    /// Whether to use compact notation, e.g., `$1.2K`.
+    pub compact: bool,
+}
+
+impl Default for CurrencyFormatOptions {
+    fn default() -> Self {
+        Self {
+            base: BaseFormatOptions::default(),
+            currency: "USD".to_string(),
+            display: CurrencyDisplay::default(),
+            compact: false,
+        }
+    }
+}
+
+/// The length of the suffixes used in compact notation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CompactDisplay {
+    /// E.g., `1.2K`.
+    #[default]
+    Short,
+    /// E.g., `1.2 thousand`.
+    Long,
+}
+
+/// How to display the currency of a number.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurrencyDisplay {
+    /// E.g., `CA$1.00`.
+    #[default]
+    Symbol,
+    /// E.g., `$1.00`.
+    NarrowSymbol,
+    /// E.g., `CAD 1.00`.
+    Code,
+    /// E.g., `1.00 Canadian dollars`.
+    Name,
+}
+
+/// The display properties of a currency.
+struct Currency<'a> {
+    code: &'a str,
+    symbol: &'a str,
+    narrow_symbol: &'a str,
+    name: &'a str,
+    /// The number of decimal places of the currency's minor unit.
+    decimals: usize,
+}
+
+impl<'a> Currency<'a> {
+    const ALL: [Currency<'static>; 9] = [
+        Currency::new("USD", "$", "$", "US dollars", 2),
+        Currency::new("EUR", "€", "€", "euros", 2),
+        Currency::new("GBP", "£", "£", "British pounds", 2),
+        Currency::new("JPY", "¥", "¥", "Japanese yen", 0),
+        Currency::new("CHF", "CHF", "CHF", "Swiss francs", 2),
+        Currency::new("CAD", "CA$", "$", "Canadian dollars", 2),
+        Currency::new("AUD", "A$", "$", "Australian dollars", 2),
+        Currency::new("CNY", "CN¥", "¥", "Chinese yuan", 2),
+        Currency::new("INR", "₹", "₹", "Indian rupees", 2),
+    ];
+
+    const fn new(
+        code: &'a str,
+        symbol: &'a str,
+        narrow_symbol: &'a str,
+        name: &'a str,
+        decimals: usize,
+    ) -> Self {
+        Self {
+            code,
+            symbol,
+            narrow_symbol,
+            name,
+            decimals,
+        }
+    }
+
+    /// Finds a currency by its code, using the code as the symbol and name of
+    /// unknown currencies.
+    fn from_code(code: &'a str) -> Self {
+        Currency::ALL
+            .into_iter()
+            .find(|currency| currency.code.eq_ignore_ascii_case(code))
+            .unwrap_or(Currency::new(code, code, code, code, 2))
+    }
+}
+
+// Formatting //
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Formats the number for display with the separators of a locale and
+    /// optional compact or percent notation.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_u256, format::FormatOptions};
+    ///
+    /// let mut options = FormatOptions::default();
+    /// options.percent = true;
+    /// options.base.decimals = Some(2);
+    /// assert_eq!(fixed_u256!(0.1325e18).format(&options), "13.25%");
+    /// ```
+    pub fn format(&self, options: &FormatOptions) -> String {
+        let base = &options.base;
+        let mut number: Number<Wide<T>> = Number::new(self);
+        if options.percent {
+            number = number.percent();
+        }
+
+        let default_decimals = match options.percent {
+            true => 0,
+            false => self.decimals().into(),
+        };
+        let mut result = match options.compact_display {
+            Some(display) => number.fmt_compact(base, display, 0),
+            None => {
+                let max = base.decimals.map_or(default_decimals, usize::from);
+                let min = if base.trailing_zeros { max } else { 0 };
+                number.fmt(base, min, max)
+            }
+        };
+        if options.percent {
+            result = format!("{result}{}%", base.locale.percent_separator);
+        }
+        format!("{}{result}", self.sign())
+    }
+
+    /// Formats the number as an amount of a currency with the separators of a
+    /// locale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_i256, format::CurrencyFormatOptions};
+    ///
+    /// let options = CurrencyFormatOptions::default();
+    /// assert_eq!(fixed_i256!(-1_234.5e18).format_currency(&options), "-$1,234.50");
+    /// ```
+    pub fn format_currency(&self, options: &CurrencyFormatOptions) -> String {
+        let base = &options.base;
+        let locale = &base.locale;
+        let currency = Currency::from_code(&options.currency);
+        let number: Number<Wide<T>> = Number::new(self);
+
+        let result = if options.compact {
+            let min = match base.trailing_zeros {
+                true => base.decimals.map_or(0, usize::from),
+                false => 0,
+            };
+            number.fmt_compact(base, CompactDisplay::Short, min)
+        } else {
+            let max = base.decimals.map_or(currency.decimals, usize::from);
+            let min = match base.trailing_zeros {
+                true => max,
+                false => currency.decimals.min(max),
+            };
+            number.fmt(base, min, max)
+        };
+
+        let symbol = match options.display {
+            CurrencyDisplay::Symbol => currency.symbol,
+            CurrencyDisplay::NarrowSymbol => currency.narrow_symbol,
+            CurrencyDisplay::Code => currency.code,
+            CurrencyDisplay::Name => {
+                return format!("{}{result} {}", self.sign(), currency.name);
+            }
+        };
+        let separator = match symbol.ends_with(char::is_alphabetic) {
+            true if locale.currency_separator.is_empty() => "\u{a0}",
+            _ => locale.currency_separator,
+        };
+        match locale.currency_after {
+            true => format!("{}{result}{separator}{symbol}", self.sign()),
+            false => format!("{}{symbol}{separator}{result}", self.sign()),
+        }
+    }
+}
+
+/// The absolute value of a number as `abs / 10^scale`.
+#[derive(Clone)]
+struct Number<W> {
+    sign: FixedPointSign,
+    abs: W,
+    scale: usize,
+}
+
+impl<W: WideValue> Number<W> {
+    fn new<T, const D: u8>(value: &FixedPoint<T, D>) -> Self
+    where
+        T: FixedPointValue,
+        W: From<T::Unsigned>,
+    {
+        Self {
+            sign: value.sign(),
+            abs: value.raw().unsigned_abs().into(),
+            scale: value.decimals().into(),
+        }
+    }
+
+    /// Multiplies the number by 100.
+    fn percent(self) -> Self {
+        match self.scale.checked_sub(2) {
+            Some(scale) => Self { scale, ..self },
+            None => Self {
+                abs: self.abs * W::exp10(2 - self.scale),
+                scale: 0,
+                ..self
+            },
+        }
+    }
+
+    /// Rounds the number to at most `places` decimal places.
+    fn round(&self, places: usize, rounding: RoundingMode) -> Self {
+        if self.scale <= places {
+            return self.clone();
+        }
+        let divisor = W::exp10(self.scale - places);
+        let (quotient, remainder) = self.abs.clone().div_mod(divisor.clone());
+        Self {
+            sign: self.sign,
+            abs: rounding.round(self.sign, quotient, remainder, divisor),
+            scale: places,
+        }
+    }
+
+    /// The number of digits before the decimal point, which is negative for
+    /// numbers with leading zeros after the decimal point.
+    fn integer_digits(&self) -> isize {
+        if self.abs.is_zero() {
+            return 1;
+        }
+        self.abs.to_string().len() as isize - self.scale as isize
+    }
+
+    /// Formats the absolute value with `min..=max` decimal places.
+    fn fmt(&self, options: &BaseFormatOptions, min: usize, max: usize) -> String {
+        let locale = &options.locale;
+        let rounded = self.round(max, options.rounding);
+        let digits = format!(
+            "{:0>width$}",
+            rounded.abs.to_string(),
+            width = rounded.scale + 1
+        );
+        let (int, fraction) = digits.split_at(digits.len() - rounded.scale);
+        let fraction = format!("{:0<min$}", fraction.trim_end_matches('0'));
+
+        let mut result = match options.group {
+            true => group_digits(int, locale),
+            false => int.to_string(),
+        };
+        if !fraction.is_empty() {
+            result.push(locale.decimal);
+            result.push_str(&fraction);
+        }
+        result
+    }
+
+    /// Formats the absolute value in compact notation with at least `min`
+    /// decimal places.
+    fn fmt_compact(
+        &self,
+        options: &BaseFormatOptions,
+        display: CompactDisplay,
+        min: usize,
+    ) -> String {
+        let mut units = options.locale.compact_units.to_vec();
+        units.sort_by_key(|unit| unit.exponent);
+        let magnitude = self.integer_digits() - 1;
+        let mut index = units
+            .iter()
+            .rposition(|unit| unit.exponent as isize <= magnitude);
+
+        loop {
+            let exponent = index.map_or(0, |i| units[i].exponent);
+            let scaled = Self {
+                sign: self.sign,
+                abs: self.abs.clone(),
+                scale: self.scale + exponent,
+            };
+            // Default to 2 significant digits without dropping integer digits.
+            let max = options
+                .decimals
+                .map_or((2 - scaled.integer_digits()).max(0) as usize, usize::from);
+            let rounded = scaled.round(max, options.rounding);
+
+            // Rounding can carry into the next unit, e.g., 999,999 is 1M.
+            let next = index.map_or(0, |i| i + 1);
+            if let Some(unit) = units.get(next) {
+                if rounded.integer_digits() > (unit.exponent - exponent) as isize {
+                    index = Some(next);
+                    continue;
+                }
+            }
+
+            let mut result = rounded.fmt(options, min, max);
+            if let Some(i) = index {
+                result.push_str(match display {
+                    CompactDisplay::Short => units[i].short,
+                    CompactDisplay::Long => units[i].long,
+                });
+            }
+            return result;
+        }
+    }
+}
+
+/// Inserts the locale's group separators into a string of integer digits.
+fn group_digits(digits: &str, locale: &Locale) -> String {
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    let mut size = locale.primary_group;
+    while size > 0 && end > size {
+        groups.push(&digits[end - size..end]);
+        end -= size;
+        size = locale.secondary_group;
+    }
+    groups.push(&digits[..end]);
+    groups.reverse();
+
+    let mut separator = [0; 4];
+    groups.join(&*locale.group.encode_utf8(&mut separator))
+}
+
+// Parsing //
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Parses a number formatted for display, e.g., by [`FixedPoint::format`]
+    /// or [`FixedPoint::format_currency`], using the separators of a locale.
+    ///
+    /// The number may have:
+    /// - Group separators in its integer part. In locales that group with
+    ///   spaces, any whitespace is accepted, e.g., `"1 234,56"`.
+    /// - A leading sign or accounting parentheses for negatives, e.g.,
+    ///   `"(3.40)"`.
+    /// - A currency symbol, code, or name before or after it, e.g.,
+    ///   `"$1,234.56"` or `"1.234,56 €"`.
+    /// - A compact unit of the locale, e.g., `"1.2K"`, which is matched
+    ///   ignoring ASCII case.
+    /// - A trailing `%`, which divides the number by 100.
+    ///
+    /// The value is parsed without rounding, so numbers with more decimal
+    /// places than `D` after applying units and percentages are rejected.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ethers::types::I256;
+    /// use fixedpointmath::{fixed, format::Locale, FixedPoint};
+    ///
+    /// let parse = |s| FixedPoint::<I256>::parse_formatted(s, &Locale::EN_US);
+    /// assert_eq!(parse("$1,234.56"), Ok(fixed!(1_234.56e18)));
+    /// assert_eq!(parse("1.2K"), Ok(fixed!(1_200e18)));
+    /// assert_eq!(parse("12.5%"), Ok(fixed!(0.125e18)));
+    /// assert_eq!(parse("(3.40)"), Ok(fixed!(-3.4e18)));
+    /// ```
+    pub fn parse_formatted(s: &str, locale: &Locale) -> Result<Self> {
+        let mut rest = s.trim();
+        let mut is_negative = false;
+        let mut exponent = 0_i64;
+
+        if let Some(inner) = rest.strip_prefix('(').and_then(|r| r.strip_suffix(')')) {
+            is_negative = true;
+            rest = inner.trim();
+        }
+        let has_sign = strip_sign(&mut rest, &mut is_negative);
+
+        // Currency symbols, codes, and names, longest first so that, e.g.,
+        // `CA$` isn't matched as `$`.
+        let mut currencies = Currency::ALL
+            .iter()
+            .flat_map(|c| [c.symbol, c.narrow_symbol, c.code, c.name])
+            .collect::<Vec<_>>();
+        currencies.sort_by_key(|currency| Reverse(currency.len()));
+        if let Some(currency) = currencies.iter().find(|c| rest.starts_with(*c)) {
+            rest = rest[currency.len()..].trim_start();
+            if !has_sign {
+                strip_sign(&mut rest, &mut is_negative);
+            }
+        }
+        if let Some(currency) = currencies.iter().find(|c| rest.ends_with(*c)) {
+            rest = rest[..rest.len() - currency.len()].trim_end();
+        }
+
+        if let Some(number) = rest.strip_suffix('%') {
+            exponent -= 2;
+            rest = number.trim_end();
+        }
+
+        // Compact units, longest first so that, e.g., `Mio.` isn't matched as
+        // `M`.
+        let mut units = locale
+            .compact_units
+            .iter()
+            .flat_map(|unit| [(unit.short.trim(), unit), (unit.long.trim(), unit)])
+            .collect::<Vec<_>>();
+        units.sort_by_key(|(suffix, _)| Reverse(suffix.len()));
+        let unit = units.into_iter().find(|(suffix, _)| {
+            let start = rest.len().saturating_sub(suffix.len());
+            rest.is_char_boundary(start) && rest[start..].eq_ignore_ascii_case(suffix)
+        });
+        if let Some((suffix, unit)) = unit {
+            exponent += unit.exponent as i64;
+            rest = rest[..rest.len() - suffix.len()].trim_end();
+        }
+
+        // Normalize the number's separators, checking each character.
+        let start = rest.as_ptr() as usize - s.as_ptr() as usize;
+        if rest.is_empty() {
+            return Err(FixedPointError::parse(s, start, "expected digits"));
+        }
+        let mut normalized = String::from(if is_negative { "-" } else { "" });
+        let mut found_decimal = false;
+        // The offset and number of digits of each group in the integer part.
+        let mut groups = vec![(start, 0)];
+        for (i, c) in rest.char_indices() {
+            let is_group = c == locale.group
+                || (locale.group.is_whitespace() && c.is_whitespace())
+                || (locale.group == '’' && c == '\'');
+            if c.is_ascii_digit() {
+                normalized.push(c);
+                if !found_decimal {
+                    groups.last_mut().unwrap().1 += 1;
+                }
+            } else if c == locale.decimal && !found_decimal && i > 0 {
+                normalized.push('.');
+                found_decimal = true;
+            } else if is_group && !found_decimal && i > 0 {
+                groups.push((start + i, 0));
+            } else {
+                return Err(FixedPointError::parse(
+                    s,
+                    start + i,
+                    format!("unexpected character {c:?}"),
+                ));
+            }
+        }
+
+        // Check the group sizes so that, e.g., `"0.5"` isn't parsed as `5` in
+        // a locale that groups with `.`.
+        if groups.len() > 1 {
+            let secondary = locale.secondary_group;
+            let primary = locale.primary_group;
+            let first_max = if groups.len() > 2 { secondary } else { primary };
+            if groups[0].1 > first_max {
+                return Err(FixedPointError::parse(
+                    s,
+                    groups[1].0,
+                    format!("expected at most {first_max} digits before the group separator"),
+                ));
+            }
+            for (i, &(offset, digits)) in groups.iter().enumerate().skip(1) {
+                let expected = if i + 1 == groups.len() {
+                    primary
+                } else {
+                    secondary
+                };
+                if digits != expected {
+                    return Err(FixedPointError::parse(
+                        s,
+                        offset,
+                        format!("expected {expected} digits after the group separator"),
+                    ));
+                }
+            }
+        }
+
+        parse::<T>(&format!("{normalized}e{exponent}"), D.into())
+            .map(Self::new)
+            .map_err(|err| match err {
+                FixedPointError::Parse { reason, .. } => FixedPointError::parse(s, start, reason),
+                err => err,
+            })
+    }
+}
+
+/// Strips a leading sign from a string, returning whether there was one.
+fn strip_sign(s: &mut &str, is_negative: &mut bool) -> bool {
+    let Some(sign) = s
+        .chars()
+        .next()
+        .filter(|c| matches!(c, '-' | '+' | '\u{2212}'))
+    else {
+        return false;
+    };
+    *is_negative |= sign != '+';
+    *s = s[sign.len_utf8()..].trim_start();
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+
+    use super::*;
+    use crate::{fixed, fixed_i256, fixed_u256};
+
+    fn options(f: impl FnOnce(&mut FormatOptions)) -> FormatOptions {
+        let mut options = FormatOptions::default();
+        f(&mut options);
+        options
+    }
+
+    fn currency_options(f: impl FnOnce(&mut CurrencyFormatOptions)) -> CurrencyFormatOptions {
+        let mut options = CurrencyFormatOptions::default();
+        f(&mut options);
+        options
+    }
+
+    #[test]
+    fn test_format() {
+        let value: FixedPoint<I256> = fixed!(-1_234_567.125e18);
+        assert_eq!(value.format(&FormatOptions::default()), "-1,234,567.125");
+        assert_eq!(
+            value.format(&options(|o| o.base.group = false)),
+            "-1234567.125"
+        );
+        assert_eq!(fixed_u256!(0).format(&FormatOptions::default()), "0");
+        assert_eq!(fixed_u256!(0.5e18).format(&FormatOptions::default()), "0.5");
+
+        // Rounding and trailing zeros.
+        assert_eq!(
+            value.format(&options(|o| o.base.decimals = Some(2))),
+            "-1,234,567.13"
+        );
+        assert_eq!(
+            value.format(&options(|o| {
+                o.base.decimals = Some(2);
+                o.base.rounding = RoundingMode::HalfEven;
+            })),
+            "-1,234,567.12"
+        );
+        assert_eq!(
+            value.format(&options(|o| {
+                o.base.decimals = Some(0);
+                o.base.rounding = RoundingMode::Floor;
+            })),
+            "-1,234,568"
+        );
+        assert_eq!(
+            fixed_u256!(1.5e18).format(&options(|o| o.base.trailing_zeros = true)),
+            "1.500000000000000000"
+        );
+        assert_eq!(
+            fixed_u256!(1.5e18).format(&options(|o| {
+                o.base.decimals = Some(4);
+                o.base.trailing_zeros = true;
+            })),
+            "1.5000"
+        );
+        let usdc = FixedPoint::<U256, 6>::new(1_000_000_u128);
+        assert_eq!(
+            usdc.format(&options(|o| o.base.trailing_zeros = true)),
+            "1.000000"
+        );
+
+        // Percentages.
+        let rate = fixed_u256!(0.13256e18);
+        assert_eq!(rate.format(&options(|o| o.percent = true)), "13%");
+        assert_eq!(
+            rate.format(&options(|o| {
+                o.percent = true;
+                o.base.decimals = Some(2);
+            })),
+            "13.26%"
+        );
+        let whole = FixedPoint::<U256, 0>::new(3_u128);
+        assert_eq!(whole.format(&options(|o| o.percent = true)), "300%");
+    }
+
+    #[test]
+    fn test_format_compact() {
+        let short = options(|o| o.compact_display = Some(CompactDisplay::Short));
+        let long = options(|o| o.compact_display = Some(CompactDisplay::Long));
+        for (value, expected) in [
+            (fixed!(0), "0"),
+            (fixed!(0.0123e18), "0.012"),
+            (fixed!(1.234e18), "1.2"),
+            (fixed!(123.4e18), "123"),
+            (fixed!(999.7e18), "1K"),
+            (fixed!(1_234e18), "1.2K"),
+            (fixed!(12_345e18), "12K"),
+            (fixed!(123_456e18), "123K"),
+            (fixed!(999_999e18), "1M"),
+            (fixed!(-3_400_000e18), "-3.4M"),
+            (fixed!(5e27), "5B"),
+            (fixed!(1e33), "1,000T"),
+        ] {
+            let value: FixedPoint<I256> = value;
+            assert_eq!(value.format(&short), expected, "{value:#}");
+        }
+        assert_eq!(fixed_i256!(1_234e18).format(&long), "1.2 thousand");
+        assert_eq!(
+            fixed_i256!(1_234_567e18).format(&options(|o| {
+                o.compact_display = Some(CompactDisplay::Short);
+                o.base.decimals = Some(3);
+            })),
+            "1.235M"
+        );
+        assert_eq!(
+            fixed_i256!(1_234_567e18).format(&options(|o| {
+                o.compact_display = Some(CompactDisplay::Short);
+                o.base.locale = Locale::EN_IN;
+            })),
+            "12L"
+        );
+    }
+
+    #[test]
+    fn test_format_locales() {
+        let value = fixed_i256!(-1_234_567.891e18);
+        let format = |locale: Locale| value.format(&options(|o| o.base.locale = locale));
+        assert_eq!(format(Locale::EN_US), "-1,234,567.891");
+        assert_eq!(format(Locale::EN_IN), "-12,34,567.891");
+        assert_eq!(format(Locale::DE_DE), "-1.234.567,891");
+        assert_eq!(format(Locale::DE_CH), "-1’234’567.891");
+        assert_eq!(format(Locale::FR_FR), "-1\u{202f}234\u{202f}567,891");
+
+        let rate = fixed_u256!(0.125e18);
+        let percent = |locale: Locale| {
+            rate.format(&options(|o| {
+                o.percent = true;
+                o.base.decimals = Some(1);
+                o.base.locale = locale;
+            }))
+        };
+        assert_eq!(percent(Locale::EN_US), "12.5%");
+        assert_eq!(percent(Locale::DE_DE), "12,5\u{a0}%");
+        assert_eq!(percent(Locale::FR_FR), "12,5\u{202f}%");
+
+        assert_eq!(Locale::from_tag("en-US"), Some(Locale::EN_US));
+        assert_eq!(Locale::from_tag("en_in"), Some(Locale::EN_IN));
+        assert_eq!(Locale::from_tag("de-AT"), Some(Locale::DE_DE));
+        assert_eq!(Locale::from_tag("fr"), Some(Locale::FR_FR));
+        assert_eq!(Locale::from_tag("ja-JP"), None);
+
+        // Group sizes of `0` turn grouping off.
+        let format = |primary_group, secondary_group| {
+            value.format(&options(|o| {
+                o.base.locale = Locale {
+                    primary_group,
+                    secondary_group,
+                    ..Locale::EN_US
+                }
+            }))
+        };
+        assert_eq!(format(0, 3), "-1234567.891");
+        assert_eq!(format(3, 0), "-1234,567.891");
+
+        // Compact units needn't be ordered.
+        let locale = Locale {
+            compact_units: &[
+                CompactUnit {
+                    exponent: 6,
+                    short: "M",
+                    long: " million",
+                },
+                CompactUnit {
+                    exponent: 3,
+                    short: "K",
+                    long: " thousand",
+                },
+            ],
+            ..Locale::EN_US
+        };
+        let compact = |value: FixedPoint<I256>| {
+            value.format(&options(|o| {
+                o.base.locale = locale;
+                o.compact_display = Some(CompactDisplay::Short);
+            }))
+        };
+        assert_eq!(compact(value), "-1.2M");
+        assert_eq!(compact(fixed!(999_999e18)), "1M");
+        assert_eq!(compact(fixed!(12_345e18)), "12K");
+    }
+
+    #[test]
+    fn test_format_currency() {
+        let value = fixed_i256!(1_234.5e18);
+        assert_eq!(value.format_currency(&Default::default()), "$1,234.50");
+        assert_eq!((-value).format_currency(&Default::default()), "-$1,234.50");
+        assert_eq!(
+            value.format_currency(&currency_options(|o| o.base.decimals = Some(0))),
+            "$1,235"
+        );
+        assert_eq!(
+            value.format_currency(&currency_options(|o| o.base.decimals = Some(4))),
+            "$1,234.50"
+        );
+        assert_eq!(
+            value.format_currency(&currency_options(|o| {
+                o.base.decimals = Some(4);
+                o.base.trailing_zeros = true;
+            })),
+            "$1,234.5000"
+        );
+        assert_eq!(
+            value.format_currency(&currency_options(|o| o.compact = true)),
+            "$1.2K"
+        );
+
+        // Currencies and displays.
+        let format = |currency: &str, display| {
+            value.format_currency(&currency_options(|o| {
+                o.currency = currency.into();
+                o.display = display;
+            }))
+        };
+        assert_eq!(format("EUR", CurrencyDisplay::Symbol), "€1,234.50");
+        assert_eq!(format("JPY", CurrencyDisplay::Symbol), "¥1,235");
+        assert_eq!(format("CAD", CurrencyDisplay::Symbol), "CA$1,234.50");
+        assert_eq!(format("CAD", CurrencyDisplay::NarrowSymbol), "$1,234.50");
+        assert_eq!(format("CHF", CurrencyDisplay::Symbol), "CHF\u{a0}1,234.50");
+        assert_eq!(format("usd", CurrencyDisplay::Code), "USD\u{a0}1,234.50");
+        assert_eq!(format("USD", CurrencyDisplay::Name), "1,234.50 US dollars");
+        assert_eq!(format("XYZ", CurrencyDisplay::Symbol), "XYZ\u{a0}1,234.50");
+
+        // Locales.
+        let format = |locale: Locale| {
+            value.format_currency(&currency_options(|o| {
+                o.currency = "EUR".into();
+                o.base.locale = locale;
+            }))
+        };
+        assert_eq!(format(Locale::DE_DE), "1.234,50\u{a0}€");
+        assert_eq!(format(Locale::FR_FR), "1\u{202f}234,50\u{a0}€");
+        assert_eq!(format(Locale::DE_CH), "€\u{a0}1’234.50");
+    }
+
+    #[test]
+    fn test_parse_formatted() {
+        let parse = |s: &str, locale: &Locale| FixedPoint::<I256>::parse_formatted(s, locale);
+        let en = &Locale::EN_US;
+        assert_eq!(parse("1,234.56", en), Ok(fixed!(1_234.56e18)));
+        assert_eq!(parse("  -$1,234.56 ", en), Ok(fixed!(-1_234.56e18)));
+        assert_eq!(parse("$-1,234.56", en), Ok(fixed!(-1_234.56e18)));
+        assert_eq!(parse("($3.40)", en), Ok(fixed!(-3.4e18)));
+        assert_eq!(parse("\u{2212}7", en), Ok(fixed!(-7e18)));
+        assert_eq!(parse("+7", en), Ok(fixed!(7e18)));
+        assert_eq!(parse("CA$5", en), Ok(fixed!(5e18)));
+        assert_eq!(parse("USD\u{a0}5", en), Ok(fixed!(5e18)));
+        assert_eq!(parse("5 US dollars", en), Ok(fixed!(5e18)));
+        assert_eq!(parse("1.2k", en), Ok(fixed!(1_200e18)));
+        assert_eq!(parse("$3.4M", en), Ok(fixed!(3_400_000e18)));
+        assert_eq!(parse("2 billion", en), Ok(fixed!(2e27)));
+        assert_eq!(parse("12.5%", en), Ok(fixed!(0.125e18)));
+        assert_eq!(
+            parse("-0.000000000000000012%", en),
+            Err(FixedPointError::parse(
+                "-0.000000000000000012%",
+                1,
+                "more than 18 decimal places"
+            ))
+        );
+        assert_eq!(parse("1.5L", &Locale::EN_IN), Ok(fixed!(150_000e18)));
+        assert_eq!(parse("1,50,000", &Locale::EN_IN), Ok(fixed!(150_000e18)));
+
+        // Locale separators.
+        let fr = &Locale::FR_FR;
+        assert_eq!(parse("1 234,56", fr), Ok(fixed!(1_234.56e18)));
+        assert_eq!(parse("1\u{202f}234,56\u{a0}€", fr), Ok(fixed!(1_234.56e18)));
+        assert_eq!(parse("12,5\u{202f}%", fr), Ok(fixed!(0.125e18)));
+        assert_eq!(parse("1,2\u{a0}Md", fr), Ok(fixed!(1.2e27)));
+        let de = &Locale::DE_DE;
+        assert_eq!(parse("1.234,56 €", de), Ok(fixed!(1_234.56e18)));
+        assert_eq!(parse("1,2 Mio.", de), Ok(fixed!(1_200_000e18)));
+        assert_eq!(parse("1'234.5", &Locale::DE_CH), Ok(fixed!(1_234.5e18)));
+
+        // Errors report the offset in the original string.
+        let error = |s: &str, locale: &Locale| match parse(s, locale) {
+            Err(FixedPointError::Parse { position, .. }) => position,
+            result => panic!("expected a parse error for {s:?}, got {result:?}"),
+        };
+        assert_eq!(error("1 234,56", en), 1);
+        assert_eq!(error("$1,234.5.6", en), 8);
+        assert_eq!(error("1.234,56", en), 5);
+        assert_eq!(error(",123", en), 0);
+        assert_eq!(error("$", en), 1);
+        assert_eq!(error("12 apples", en), 2);
+        assert_eq!(error("0.5", de), 1);
+        assert_eq!(error("1.23.456", de), 1);
+        assert_eq!(error("1234.567", de), 4);
+        assert_eq!(error("1,234,56", en), 5);
+        assert_eq!(error("1,50,000", en), 1);
+        assert!(matches!(
+            FixedPoint::<U256>::parse_formatted("-1", en),
+            Err(FixedPointError::NegativeToUnsigned { .. })
+        ));
+
+        // Formatting and parsing round trip.
+        let value = fixed_i256!(-1_234_567.891e18);
+        for locale in Locale::ALL {
+            let formatted = value.format(&options(|o| o.base.locale = locale));
+            assert_eq!(parse(&formatted, &locale), Ok(value), "{formatted}");
+            let formatted = value.format_currency(&currency_options(|o| {
+                o.base.locale = locale;
+                o.base.decimals = Some(3);
+                o.currency = "EUR".into();
+            }));
+            assert_eq!(parse(&formatted, &locale), Ok(value), "{formatted}");
+        }
+    }
+}
@@ -0,0 +1,300 @@
+use alloc::{format, string::ToString};
+use core::{
+    cmp::Ordering,
+    fmt,
+    ops::{BitOr, Div, Shr},
+};
+
+use primitive_types::U256;
+
+use crate::{
+    FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, Operation, Result, RoundingMode,
+    DEFAULT_DECIMALS,
+};
+
+/// A minimal two's complement 256-bit signed integer used for the
+/// intermediate math in `exp`, `ln`, and `pow` so they don't depend on a
+/// specific signed integer implementation.
+///
+/// The method names and semantics mirror ethers-rs' `I256` and Solidity's
+/// `int256` so the ported algorithms read the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Int256(U256);
+
+impl Int256 {
+    pub const ZERO: Self = Int256(U256::zero());
+
+    /// The largest absolute value of a positive `Int256`, i.e., `2^255 - 1`.
+    fn max_abs() -> U256 {
+        U256::MAX >> 1
+    }
+
+    pub fn from_raw(raw: U256) -> Self {
+        Int256(raw)
+    }
+
+    pub fn into_raw(self) -> U256 {
+        self.0
+    }
+
+    /// Converts a `FixedPointValue` into an `Int256`, returning an error if
+    /// it's out of range.
+    pub fn try_from_value<T: FixedPointValue>(value: T) -> Result<Self> {
+        let sign = FixedPointSign::from(!value.is_negative());
+        value
+            .clone()
+            .unsigned_abs()
+            .to_u256()
+            .ok()
+            .and_then(|abs| Self::checked_from_sign_and_abs(sign, abs))
+            .ok_or_else(|| FixedPointError::OutOfRange {
+                value: format!("{value:?}"),
+                target: "int256",
+            })
+    }
+
+    /// Converts this instance into a `FixedPointValue`, returning an error if
+    /// it's out of the range of `T`.
+    pub fn try_into_value<T: FixedPointValue>(self) -> Result<T> {
+        let (sign, abs) = self.into_sign_and_abs();
+        Ok(FixedPoint::<T, 0>::from_sign_and_abs(sign, T::Unsigned::from_u256(abs)?)?.raw())
+    }
+
+    /// Converts a `FixedPoint` into an `Int256` with 18 decimals, rounding
+    /// any decimal places beyond the 18th with the given mode. Returns an
+    /// error if the result is out of range.
+    pub fn try_from_fixed<T: FixedPointValue, const D: u8>(
+        value: &FixedPoint<T, D>,
+        rounding: RoundingMode,
+    ) -> Result<Self> {
+        let sign = value.sign();
+        value
+            .raw()
+            .unsigned_abs()
+            .to_u256()
+            .ok()
+            .and_then(|abs| rescale(sign, abs, value.decimals(), DEFAULT_DECIMALS, rounding))
+            .and_then(|abs| Self::checked_from_sign_and_abs(sign, abs))
+            .ok_or_else(|| FixedPointError::OutOfRange {
+                value: value.to_string(),
+                target: "int256",
+            })
+    }
+
+    /// Converts this instance with 18 decimals into a `FixedPoint` with `D`
+    /// decimals, rounding any decimal places beyond them with the given mode.
+    /// Returns an error if the result is out of the range of `T`.
+    pub fn try_into_fixed<T: FixedPointValue, const D: u8>(
+        self,
+        rounding: RoundingMode,
+    ) -> Result<FixedPoint<T, D>> {
+        let (sign, abs) = self.into_sign_and_abs();
+        let abs = rescale(sign, abs, DEFAULT_DECIMALS, D, rounding)
+            .ok_or_else(|| FixedPointError::overflow(Operation::Rescale(D), &[self]))?;
+        FixedPoint::from_sign_and_abs(sign, T::Unsigned::from_u256(abs)?)
+    }
+
+    pub fn from_i128(value: i128) -> Self {
+        let sign = FixedPointSign::from(value >= 0);
+        Self::from_sign_and_abs_wrapping(sign, U256::from(value.unsigned_abs()))
+    }
+
+    /// Creates an instance from a sign and absolute value, returning `None` if
+    /// the value is out of range.
+    pub fn checked_from_sign_and_abs(sign: FixedPointSign, abs: U256) -> Option<Self> {
+        let max_abs = match sign {
+            FixedPointSign::Positive => Self::max_abs(),
+            FixedPointSign::Negative => Self::max_abs() + 1,
+        };
+        if abs > max_abs {
+            return None;
+        }
+        Some(Self::from_sign_and_abs_wrapping(sign, abs))
+    }
+
+    fn from_sign_and_abs_wrapping(sign: FixedPointSign, abs: U256) -> Self {
+        let value = Int256(abs);
+        match sign {
+            FixedPointSign::Positive => value,
+            FixedPointSign::Negative => value.wrapping_neg(),
+        }
+    }
+
+    pub fn into_sign_and_abs(self) -> (FixedPointSign, U256) {
+        if self.is_negative() {
+            (FixedPointSign::Negative, self.wrapping_neg().0)
+        } else {
+            (FixedPointSign::Positive, self.0)
+        }
+    }
+
+    pub fn is_negative(self) -> bool {
+        self.0.bit(255)
+    }
+
+    pub fn low_usize(self) -> usize {
+        self.0.low_u64() as usize
+    }
+
+    pub fn wrapping_neg(self) -> Self {
+        Int256((!self.0).overflowing_add(U256::one()).0)
+    }
+
+    pub fn wrapping_add(self, rhs: Self) -> Self {
+        Int256(self.0.overflowing_add(rhs.0).0)
+    }
+
+    pub fn wrapping_sub(self, rhs: Self) -> Self {
+        Int256(self.0.overflowing_sub(rhs.0).0)
+    }
+
+    /// The low 256 bits of a two's complement product are the same as those of
+    /// the unsigned product.
+    pub fn wrapping_mul(self, rhs: Self) -> Self {
+        Int256(self.0.overflowing_mul(rhs.0).0)
+    }
+
+    /// Divides, truncating toward zero. `MIN / -1` wraps to `MIN`.
+    ///
+    /// # Panics
+    ///
+    /// If `rhs` is zero.
+    pub fn wrapping_div(self, rhs: Self) -> Self {
+        let (lhs_sign, lhs_abs) = self.into_sign_and_abs();
+        let (rhs_sign, rhs_abs) = rhs.into_sign_and_abs();
+        let sign = lhs_sign.flip_if(rhs_sign.is_negative());
+        Self::from_sign_and_abs_wrapping(sign, lhs_abs / rhs_abs)
+    }
+
+    pub fn wrapping_shl(self, rhs: usize) -> Self {
+        if rhs >= 256 {
+            return Self::ZERO;
+        }
+        Int256(self.0 << rhs)
+    }
+
+    /// Arithmetic shift right, i.e., a shift that preserves the sign.
+    pub fn asr(self, rhs: usize) -> Self {
+        if self.is_negative() {
+            if rhs >= 256 {
+                return Int256(U256::MAX);
+            }
+            Int256(!((!self.0).shr(rhs)))
+        } else if rhs >= 256 {
+            Self::ZERO
+        } else {
+            Int256(self.0.shr(rhs))
+        }
+    }
+}
+
+/// Rescales an absolute value from one number of decimals to another, rounding
+/// with the given mode. Returns `None` if the result overflows.
+fn rescale(
+    sign: FixedPointSign,
+    abs: U256,
+    from: u8,
+    to: u8,
+    rounding: RoundingMode,
+) -> Option<U256> {
+    if to >= from {
+        return abs.checked_mul(U256::exp10((to - from).into()));
+    }
+    let scale = U256::exp10((from - to).into());
+    let (quotient, remainder) = abs.div_mod(scale);
+    Some(rounding.round(sign, quotient, remainder, scale))
+}
+
+impl From<u128> for Int256 {
+    fn from(value: u128) -> Self {
+        Int256(U256::from(value))
+    }
+}
+
+impl Div for Int256 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self.wrapping_div(rhs)
+    }
+}
+
+impl BitOr for Int256 {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Int256(self.0 | rhs.0)
+    }
+}
+
+impl fmt::Display for Int256 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let (sign, abs) = self.into_sign_and_abs();
+        write!(f, "{sign}{abs}")
+    }
+}
+
+impl Ord for Int256 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Flipping the sign bit maps two's complement values onto unsigned
+        // values in the same order.
+        let sign_bit = U256::one() << 255;
+        (self.0 ^ sign_bit).cmp(&(other.0 ^ sign_bit))
+    }
+}
+
+impl PartialOrd for Int256 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::I256;
+    use rand::{thread_rng, Rng};
+
+    use super::*;
+
+    #[test]
+    fn fuzz_int256() {
+        // Fuzz the operations against ethers-rs' `I256`.
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let a_raw = U256::from(rng.gen::<[u8; 32]>());
+            let b_raw = U256::from(rng.gen::<[u8; 32]>()) >> rng.gen_range(0..256);
+            let shift = rng.gen_range(0..300);
+            let (a, b) = (Int256::from_raw(a_raw), Int256::from_raw(b_raw));
+            let (ea, eb) = (I256::from_raw(a_raw), I256::from_raw(b_raw));
+
+            assert_eq!(a.wrapping_add(b).into_raw(), ea.wrapping_add(eb).into_raw());
+            assert_eq!(a.wrapping_sub(b).into_raw(), ea.wrapping_sub(eb).into_raw());
+            assert_eq!(a.wrapping_mul(b).into_raw(), ea.wrapping_mul(eb).into_raw());
+            if !b_raw.is_zero() {
+                assert_eq!(a.wrapping_div(b).into_raw(), ea.wrapping_div(eb).into_raw());
+            }
+            assert_eq!(a.asr(shift).into_raw(), ea.asr(shift).into_raw());
+            assert_eq!(
+                a.wrapping_shl(shift).into_raw(),
+                ea.wrapping_shl(shift).into_raw()
+            );
+            assert_eq!(a.cmp(&b), ea.cmp(&eb));
+            assert_eq!(a.is_negative(), ea.is_negative());
+
+            let (sign, abs) = a.into_sign_and_abs();
+            let (e_sign, e_abs) = ea.into_sign_and_abs();
+            assert_eq!(sign, e_sign.into());
+            assert_eq!(abs, e_abs);
+            assert_eq!(Int256::checked_from_sign_and_abs(sign, abs), Some(a));
+        }
+
+        assert_eq!(
+            Int256::from_i128(i128::MIN).into_raw(),
+            I256::from(i128::MIN).into_raw()
+        );
+        assert_eq!(
+            Int256::checked_from_sign_and_abs(FixedPointSign::Positive, U256::one() << 255),
+            None
+        );
+    }
+}
@@ -1,4 +1,4 @@
-//! A generic fixed point wrapper around the `U256` type from ethers-rs.
+//! A generic fixed point wrapper around 256-bit integer types.
 //!
 //! The math in this library is a heavily based on Solidity's FixedPointMath
 //! library with a few changes:
@@ -7,32 +7,89 @@
 //!   signed integers, and ensuring that the instance is bounded by the generic
 //!   type's limits.
 //! - Support for overflowing intermediate operations in `mul_div_down` and
-//!  `mul_div_up` via `U512`.
+//!   `mul_div_up` via full products of the absolute values in a type twice
+//!   their size, e.g., `u128` for `i64` or `U1024` for the `U512` absolute
+//!   values of the 256-bit types, which also allows 512-bit values.
 //!
 //! Each of the functions is fuzz tested against the Solidity implementation to
 //! ensure that the behavior is identical given values bounded by the Solidity
-//! implementation's limits.
+//! implementation's limits. The exceptions are the logarithms and
+//! exponentials with [`Accuracy::Precise`], which trade that equivalence for
+//! results within one unit in the last place at any number of decimals.
+//!
+//! ## Features
+//!
+//! - `std` *(default)*: Implements `std::error::Error` for `FixedPointError`.
+//!   Without it, the crate is `no_std` and only requires `alloc`.
+//! - `ethers` *(default)*: Adds `I256` support and conversions to and from
+//!   ethers-rs types.
+//! - `rand` *(default)*: Adds random sampling via `UniformFixedPoint`.
+//! - `alloy`: Implements `FixedPointValue` for alloy-primitives' `U256` and
+//!   `I256`, with lossless conversions to and from the ethers flavors.
+//! - `bigint`: Implements `FixedPointValue` for num-bigint's `BigInt` and
+//!   `BigUint`, which are unbounded, so reference computations can run at
+//!   arbitrary precision with the same `FixedPoint` API.
+//! - `serde`: Implements `Serialize` and `Deserialize` for `FixedPoint` as
+//!   decimal strings, with alternative wire formats in [`serde`].
+//! - `eyre`: Adds conversion of errors into `eyre` reports.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 
+extern crate alloc;
+
+mod accuracy;
+#[cfg(feature = "alloy")]
+mod alloy;
+#[cfg(feature = "bigint")]
+mod bigint;
+mod error;
 mod fixed_point;
+pub mod format;
+mod i256;
+mod log_fixed;
 mod macros;
 mod math;
+mod parse;
+mod precise;
+#[cfg(feature = "rand")]
 mod rng;
+mod rounding;
+#[cfg(feature = "serde")]
+pub mod serde;
 mod sign;
+pub mod units;
 mod utils;
 mod value;
 mod value_impls;
+mod wide;
+
+#[doc(hidden)]
+pub use alloc::format as __format;
 
+pub use accuracy::*;
+pub use error::*;
+#[cfg(feature = "ethers")]
+pub use ethers::types::I256;
 pub use fixed_point::*;
+pub use log_fixed::*;
+pub use primitive_types::{U256, U512};
+#[cfg(feature = "rand")]
 pub use rng::*;
+pub use rounding::*;
 pub use sign::*;
 pub use utils::*;
 pub use value::*;
+pub use wide::WideValue;
 
 pub mod prelude {
     pub use super::{
+        accuracy::Accuracy,
         fixed, fixed_i128, fixed_i256,
         fixed_point::{Fixed, FixedPoint, ToFixed},
-        fixed_u128, fixed_u256, int256, uint256,
+        fixed_u128, fixed_u256, int256,
+        log_fixed::LogFixed,
+        rounding::RoundingMode,
+        uint256,
         value::FixedPointValue,
     };
 }
@@ -0,0 +1,215 @@
+use alloc::string::ToString;
+use core::{
+    fmt,
+    ops::{Div, DivAssign, Mul, MulAssign},
+};
+
+use crate::{
+    FixedPoint, FixedPointError, FixedPointValue, Operation, Result, RoundingMode, DEFAULT_DECIMALS,
+};
+
+/// A positive number stored as its natural logarithm, so that products,
+/// quotients, and powers of numbers far outside the range of `T` can be
+/// computed by adding, subtracting, and multiplying their logarithms.
+///
+/// Each operation rounds the logarithm to `D` decimals, which is a relative
+/// error of at most `0.5e-D` in the value. Values below 1 need a signed `T`.
+///
+/// # Example
+///
+/// ```
+/// use fixedpointmath::{fixed_i256, LogFixed};
+///
+/// // e^200 doesn't fit in an `I256` with 18 decimals, but its square root
+/// // does.
+/// let huge = LogFixed::exp(fixed_i256!(200e18));
+/// assert!(huge.to_fixed().is_err());
+/// let root = huge.checked_pow(fixed_i256!(0.5e18)).unwrap();
+/// assert_eq!(root.to_fixed(), fixed_i256!(100e18).exp());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LogFixed<T: FixedPointValue, const D: u8 = DEFAULT_DECIMALS> {
+    ln: FixedPoint<T, D>,
+}
+
+impl<T: FixedPointValue, const D: u8> LogFixed<T, D> {
+    // Constructors //
+
+    /// Creates an instance from a positive value, returning an error if it
+    /// isn't positive or its logarithm doesn't fit in `T`.
+    pub fn new(value: FixedPoint<T, D>) -> Result<Self> {
+        value.ln().map(Self::exp)
+    }
+
+    /// Creates an instance equal to `e^x`. Unlike [`FixedPoint::exp`], this
+    /// can't overflow.
+    pub fn exp(x: FixedPoint<T, D>) -> Self {
+        Self { ln: x }
+    }
+
+    // Getters //
+
+    /// Returns the natural logarithm of the value.
+    pub fn ln(&self) -> FixedPoint<T, D> {
+        self.ln.clone()
+    }
+
+    // Conversion //
+
+    /// Converts the value to a `FixedPoint`, returning an error if it doesn't
+    /// fit in `T`. Values too small to represent are zero.
+    pub fn to_fixed(&self) -> Result<FixedPoint<T, D>> {
+        self.ln().exp()
+    }
+
+    // Arithmetic //
+
+    /// Multiplies self by `other`, returning an error if the logarithm of the
+    /// product overflows `T`. The error reports the addition of the
+    /// logarithms.
+    pub fn checked_mul(self, other: Self) -> Result<Self> {
+        self.ln.checked_add(other.ln).map(Self::exp)
+    }
+
+    /// Divides self by `other`, returning an error if the logarithm of the
+    /// quotient overflows `T`. The error reports the subtraction of the
+    /// logarithms.
+    pub fn checked_div(self, other: Self) -> Result<Self> {
+        self.ln.checked_sub(other.ln).map(Self::exp)
+    }
+
+    /// Raises self to the power of `y`, returning an error if the logarithm of
+    /// the result overflows `T`.
+    pub fn checked_pow(self, y: FixedPoint<T, D>) -> Result<Self> {
+        self.ln()
+            .checked_mul(y.clone(), RoundingMode::HalfEven)
+            .map(Self::exp)
+            .map_err(|_| {
+                FixedPointError::overflow(Operation::Pow, &[self.to_string(), y.to_string()])
+            })
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> TryFrom<FixedPoint<T, D>> for LogFixed<T, D> {
+    type Error = FixedPointError;
+
+    fn try_from(value: FixedPoint<T, D>) -> Result<Self> {
+        Self::new(value)
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> TryFrom<LogFixed<T, D>> for FixedPoint<T, D> {
+    type Error = FixedPointError;
+
+    fn try_from(value: LogFixed<T, D>) -> Result<Self> {
+        value.to_fixed()
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> fmt::Debug for LogFixed<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LogFixed({self})")
+    }
+}
+
+/// Formats the value as a power of `e`, e.g., `e^1.500000000000000000`.
+impl<T: FixedPointValue, const D: u8> fmt::Display for LogFixed<T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "e^{}", self.ln)
+    }
+}
+
+// Operators //
+
+impl<T: FixedPointValue, const D: u8> Mul for LogFixed<T, D> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        self.checked_mul(other).unwrap()
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> MulAssign for LogFixed<T, D> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = self.clone() * other;
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> Div for LogFixed<T, D> {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        self.checked_div(other).unwrap()
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> DivAssign for LogFixed<T, D> {
+    fn div_assign(&mut self, other: Self) {
+        *self = self.clone() / other;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+    use eyre::Result;
+
+    use super::*;
+    use crate::{fixed, fixed_i256, fixed_u256};
+
+    #[test]
+    fn test_log_fixed() -> Result<()> {
+        // Round trips are within the relative error of rounding the logarithm.
+        let x = LogFixed::new(fixed_i256!(2e18))?;
+        assert_eq!(x.ln(), fixed!(0.693147180559945309e18));
+        assert_eq!(x.to_fixed()?, fixed!(1.999999999999999999e18));
+        let value: FixedPoint<I256> = x.try_into()?;
+        assert_eq!(value, x.to_fixed()?);
+
+        // Products, quotients, and powers beyond the range of `I256`.
+        let huge = LogFixed::exp(fixed_i256!(300e18));
+        assert!(matches!(
+            huge.to_fixed(),
+            Err(FixedPointError::ExpOverflow { .. })
+        ));
+        let product = huge * huge;
+        assert_eq!(product.ln(), fixed!(600e18));
+        assert_eq!((product / huge / huge).to_fixed()?, fixed!(1e18));
+        let tiny = huge.checked_pow(fixed!(-1e18))?;
+        assert_eq!(tiny.to_fixed()?, fixed!(0));
+        assert_eq!((huge * tiny).to_fixed()?, fixed!(1e18));
+        assert_eq!(
+            huge.checked_pow(fixed!(0.25e18))?.to_fixed()?,
+            fixed_i256!(75e18).exp()?
+        );
+        assert!(huge < product && tiny < huge);
+
+        // Values below 1 need a signed type.
+        assert!(LogFixed::<U256>::new(fixed_u256!(0.5e18)).is_err());
+        let x = LogFixed::<U256>::new(fixed_u256!(1_000e18))?.to_fixed()?;
+        assert!(x.abs_diff(fixed!(1_000e18)) <= fixed!(0.0000000000000005e18));
+
+        // Invalid inputs and logarithms that overflow are errors.
+        assert!(matches!(
+            LogFixed::new(fixed_i256!(-1e18)),
+            Err(FixedPointError::LnNonPositive { .. })
+        ));
+        let max = LogFixed::exp(FixedPoint::<I256>::MAX);
+        assert_eq!(
+            max.checked_mul(LogFixed::exp(fixed!(1))).unwrap_err(),
+            FixedPointError::overflow(Operation::Add, &[FixedPoint::<I256>::MAX, fixed!(1)])
+        );
+        let min = LogFixed::exp(FixedPoint::<I256>::MIN);
+        assert_eq!(
+            min.checked_div(LogFixed::exp(fixed!(1))).unwrap_err(),
+            FixedPointError::overflow(Operation::Sub, &[FixedPoint::<I256>::MIN, fixed!(1)])
+        );
+        assert!(max.checked_pow(fixed!(2e18)).is_err());
+        assert_eq!(
+            format!("{:?}", LogFixed::exp(fixed_i256!(1.5e18))),
+            "LogFixed(e^1.500000000000000000)"
+        );
+
+        Ok(())
+    }
+}
@@ -1,12 +1,11 @@
-// TODO: These macros can be improved in two important ways:
-//
-// 1. Support non-decimal number formats. It would be convenient to support
-//    hexadecimal and binary numbers since Rust has similar problems with those
-//    number types.
-// 2. Support expressions. Ideally, we would execute any expressions at compile
-//    time, so we could instantiate a fixed point number to represent 60 * 60 *
-//    24 seconds (for example).
-
+// TODO: These macros could support expressions. Ideally, we would execute any
+// expressions at compile time, so we could instantiate a fixed point number to
+// represent 60 * 60 * 24 seconds (for example).
+
+/// Creates a `U256` from a number literal, see [`u256_from_str`] for the
+/// accepted formats.
+///
+/// [`u256_from_str`]: crate::u256_from_str
 #[macro_export]
 macro_rules! uint256 {
     ($number:expr) => {{
@@ -15,6 +14,10 @@ macro_rules! uint256 {
     }};
 }
 
+/// Creates an `I256` from a number literal, see [`i256_from_str`] for the
+/// accepted formats.
+///
+/// [`i256_from_str`]: crate::i256_from_str
 #[macro_export]
 macro_rules! int256 {
     ($number:expr) => {{
@@ -39,7 +42,7 @@ macro_rules! fixed {
 macro_rules! fixed_u256 {
     ($number:expr) => {{
         let str = stringify!($number);
-        $crate::FixedPoint::<ethers::types::U256>::from_dec_str(str).unwrap()
+        $crate::FixedPoint::<$crate::U256>::from_dec_str(str).unwrap()
     }};
 }
 
@@ -48,7 +51,7 @@ macro_rules! fixed_u256 {
 macro_rules! fixed_i256 {
     ($number:expr) => {{
         let str = stringify!($number);
-        $crate::FixedPoint::<ethers::types::I256>::from_dec_str(str).unwrap()
+        $crate::FixedPoint::<$crate::I256>::from_dec_str(str).unwrap()
     }};
 }
 
@@ -103,6 +106,10 @@ mod tests {
             int256!(-333_333.555_555e18),
             -I256::from(333_333_555_555_u128) * I256::from(10).pow(12)
         );
+
+        // non-decimal notation
+        assert_eq!(int256!(0xff), I256::from(255));
+        assert_eq!(int256!(-0b1010), I256::from(-10));
     }
 
     #[test]
@@ -130,6 +137,10 @@ mod tests {
             uint256!(333_333.555_555e18),
             U256::from(333_333_555_555_u128) * U256::from(10).pow(12.into())
         );
+
+        // non-decimal notation
+        assert_eq!(uint256!(0xdead_beef), U256::from(0xdead_beef_u128));
+        assert_eq!(uint256!(0o17), U256::from(15));
     }
 
     #[test]
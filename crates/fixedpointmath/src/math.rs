@@ -1,221 +1,3272 @@
-use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use alloc::{format, string::ToString};
+use core::ops::Neg;
+
+use primitive_types::{U256, U512};
+
+use crate::{
+    i256::Int256,
+    precise::{self, GUARD_DIGITS, MAX_UNBOUNDED_DIGITS},
+    utils::{exp_int256, ln_int256, pow_int256},
+    wide::{Extended, Wide, WideValue, U1024},
+    Accuracy, FixedPoint, FixedPointError, FixedPointSign, FixedPointValue, Operation, Result,
+    RoundingMode, UnsignedValue, DEFAULT_DECIMALS,
+};
+
+/// The assumed maximum absolute error of `ln` in FixedPointMath.sol scaled by
+/// `1e18`. This isn't derived from its algorithm, it's the measured error of
+/// `[-1.05e-18, 0.05e-18]` rounded up with some margin.
+const LN_MAX_ERROR: u64 = 2;
+
+/// The assumed maximum relative error of `exp` in FixedPointMath.sol scaled by
+/// `1e18`, in addition to the `1e-18` from truncating its result. This isn't
+/// derived from its algorithm, it's the measured relative error of below
+/// `2e-20` rounded up with some margin.
+const EXP_MAX_RELATIVE_ERROR: u64 = 1;
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Computes the absolute value of self.
+    ///
+    /// # Panics
+    ///
+    /// If the absolute value of self overflows `T`, e.g., if self is the
+    /// minimum value of a signed integer.
+    pub fn abs(&self) -> Self {
+        Self::new(self.raw().abs())
+    }
+
+    /// Computes the absolute value of self as an unsigned value, e.g., a
+    /// `U512`, to avoid overflow.
+    pub fn unsigned_abs(&self) -> FixedPoint<T::Unsigned, D> {
+        FixedPoint::new(self.raw().unsigned_abs())
+    }
+
+    pub fn abs_diff(&self, other: Self) -> FixedPoint<T::Unsigned, D> {
+        let abs_self = self.unsigned_abs();
+        let abs_other = other.unsigned_abs();
+        if self.sign() != other.sign() {
+            abs_self + abs_other
+        } else if abs_self > abs_other {
+            abs_self - abs_other
+        } else {
+            abs_other - abs_self
+        }
+    }
+
+    // Checked Arithmetic //
+
+    /// Adds `other` to self, returning an error if the result overflows or
+    /// underflows `T`.
+    pub fn checked_add(self, other: Self) -> Result<Self> {
+        self.clone()
+            .add_signed(other.clone(), other.sign())
+            .ok_or_else(|| FixedPointError::overflow(Operation::Add, &[self, other]))
+    }
+
+    /// Subtracts `other` from self, returning an error if the result overflows
+    /// or underflows `T`.
+    pub fn checked_sub(self, other: Self) -> Result<Self> {
+        self.clone()
+            .add_signed(other.clone(), other.sign().flip())
+            .ok_or_else(|| FixedPointError::overflow(Operation::Sub, &[self, other]))
+    }
+
+    /// Computes `self * other / divisor`, rounding with the given mode, and
+    /// returns an error if the divisor is zero or the result overflows `T`.
+    pub fn checked_mul_div(
+        self,
+        other: Self,
+        divisor: Self,
+        rounding: RoundingMode,
+    ) -> Result<Self> {
+        let abs = self.mul_div_abs(&other, &divisor, rounding)?;
+        let sign = self.sign().flip_if(other.sign() != divisor.sign());
+        T::Unsigned::try_from_wide(abs)
+            .and_then(|abs| Self::from_sign_and_abs(sign, abs).ok())
+            .ok_or_else(|| FixedPointError::overflow(Operation::MulDiv, &[self, other, divisor]))
+    }
+
+    pub fn checked_mul(self, other: Self, rounding: RoundingMode) -> Result<Self> {
+        let one = self.one();
+        self.checked_mul_div(other, one, rounding)
+    }
+
+    pub fn checked_div(self, other: Self, rounding: RoundingMode) -> Result<Self> {
+        let one = self.one();
+        self.checked_mul_div(one, other, rounding)
+    }
+
+    /// Alias for [`FixedPoint::checked_mul_div`] with
+    /// [`RoundingMode::TowardZero`].
+    pub fn checked_mul_div_down(self, other: Self, divisor: Self) -> Result<Self> {
+        self.checked_mul_div(other, divisor, RoundingMode::TowardZero)
+    }
+
+    /// Alias for [`FixedPoint::checked_mul_div`] with
+    /// [`RoundingMode::AwayFromZero`].
+    pub fn checked_mul_div_up(self, other: Self, divisor: Self) -> Result<Self> {
+        self.checked_mul_div(other, divisor, RoundingMode::AwayFromZero)
+    }
+
+    /// Alias for [`FixedPoint::checked_mul`] with [`RoundingMode::TowardZero`].
+    pub fn checked_mul_down(self, other: Self) -> Result<Self> {
+        self.checked_mul(other, RoundingMode::TowardZero)
+    }
+
+    /// Alias for [`FixedPoint::checked_mul`] with
+    /// [`RoundingMode::AwayFromZero`].
+    pub fn checked_mul_up(self, other: Self) -> Result<Self> {
+        self.checked_mul(other, RoundingMode::AwayFromZero)
+    }
+
+    /// Alias for [`FixedPoint::checked_div`] with [`RoundingMode::TowardZero`].
+    pub fn checked_div_down(self, other: Self) -> Result<Self> {
+        self.checked_div(other, RoundingMode::TowardZero)
+    }
+
+    /// Alias for [`FixedPoint::checked_div`] with
+    /// [`RoundingMode::AwayFromZero`].
+    pub fn checked_div_up(self, other: Self) -> Result<Self> {
+        self.checked_div(other, RoundingMode::AwayFromZero)
+    }
+
+    /// Raises self to the power of `y`.
+    ///
+    /// The result is computed with 18 decimals of precision, as in
+    /// FixedPointMath.sol, and scaled back to `self.decimals()`. Any digits
+    /// beyond the 18th decimal place of `self` or `y` are truncated.
+    ///
+    /// As in FixedPointMath.sol, self can't be negative. Use
+    /// [`FixedPoint::pow_with`] with [`Accuracy::Precise`] or
+    /// [`FixedPoint::pow_ratio`] for powers of negative bases.
+    pub fn checked_pow(self, y: Self) -> Result<Self> {
+        let overflow = || FixedPointError::overflow(Operation::Pow, &[&self, &y]);
+        let x_int256 = Int256::try_from_fixed(&self, RoundingMode::TowardZero)?;
+        let y_int256 = Int256::try_from_fixed(&y, RoundingMode::TowardZero)?;
+
+        // If the exponent is negative, return 1 / x^abs(y).
+        let result = match y_int256.into_sign_and_abs() {
+            (FixedPointSign::Negative, abs_y) => {
+                let abs_y = Int256::checked_from_sign_and_abs(FixedPointSign::Positive, abs_y)
+                    .ok_or_else(overflow)?;
+                let abs_result = pow_int256(x_int256, abs_y)?;
+                if abs_result == Int256::ZERO {
+                    return Err(FixedPointError::division_by_zero(
+                        Operation::Pow,
+                        &[self, y],
+                    ));
+                }
+                Int256::from(10_u128.pow(36)).wrapping_div(abs_result)
+            }
+            (FixedPointSign::Positive, _) => pow_int256(x_int256, y_int256)?,
+        };
+        result
+            .try_into_fixed(RoundingMode::TowardZero)
+            .map_err(|_| overflow())
+    }
+
+    // Panicking Arithmetic //
+
+    /// Computes `self * other / divisor`, rounding with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero or the result overflows `T`. See
+    /// [`FixedPoint::checked_mul_div`] for a non-panicking version.
+    pub fn mul_div(self, other: Self, divisor: Self, rounding: RoundingMode) -> Self {
+        self.checked_mul_div(other, divisor, rounding).unwrap()
+    }
+
+    /// Computes `self * other`, rounding with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`.
+    pub fn mul_with(self, other: Self, rounding: RoundingMode) -> Self {
+        self.checked_mul(other, rounding).unwrap()
+    }
+
+    /// Computes `self / other`, rounding with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is zero or the result overflows `T`.
+    pub fn div_with(self, other: Self, rounding: RoundingMode) -> Self {
+        self.checked_div(other, rounding).unwrap()
+    }
+
+    /// Computes `self * other / divisor`, rounding towards zero. This is an
+    /// alias for [`FixedPoint::mul_div`] with [`RoundingMode::TowardZero`],
+    /// which for signed values isn't the same as [`RoundingMode::Floor`].
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero or the result overflows `T`. See
+    /// [`FixedPoint::checked_mul_div_down`] for a non-panicking version.
+    pub fn mul_div_down(self, other: Self, divisor: Self) -> Self {
+        self.mul_div(other, divisor, RoundingMode::TowardZero)
+    }
+
+    /// Computes `self * other / divisor`, rounding away from zero. This is an
+    /// alias for [`FixedPoint::mul_div`] with [`RoundingMode::AwayFromZero`],
+    /// which for signed values isn't the same as [`RoundingMode::Ceil`].
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero or the result overflows `T`. See
+    /// [`FixedPoint::checked_mul_div_up`] for a non-panicking version.
+    pub fn mul_div_up(self, other: Self, divisor: Self) -> Self {
+        self.mul_div(other, divisor, RoundingMode::AwayFromZero)
+    }
+
+    /// Alias for [`FixedPoint::mul_with`] with [`RoundingMode::TowardZero`].
+    pub fn mul_down(self, other: Self) -> Self {
+        self.mul_with(other, RoundingMode::TowardZero)
+    }
+
+    /// Alias for [`FixedPoint::mul_with`] with [`RoundingMode::AwayFromZero`].
+    pub fn mul_up(self, other: Self) -> Self {
+        self.mul_with(other, RoundingMode::AwayFromZero)
+    }
+
+    /// Alias for [`FixedPoint::div_with`] with [`RoundingMode::TowardZero`].
+    pub fn div_down(self, other: Self) -> Self {
+        self.div_with(other, RoundingMode::TowardZero)
+    }
+
+    /// Alias for [`FixedPoint::div_with`] with [`RoundingMode::AwayFromZero`].
+    pub fn div_up(self, other: Self) -> Self {
+        self.div_with(other, RoundingMode::AwayFromZero)
+    }
+
+    /// Raises self to the power of `y`. This is an alias for
+    /// [`FixedPoint::checked_pow`].
+    pub fn pow(self, y: Self) -> Result<Self> {
+        self.checked_pow(y)
+    }
+
+    // Saturating Arithmetic //
+
+    /// Adds `other` to self, clamping the result to `MIN` or `MAX` instead of
+    /// overflowing.
+    pub fn saturating_add(self, other: Self) -> Self {
+        self.clone()
+            .checked_add(other.clone())
+            .unwrap_or_else(|_| self.saturate_sum(&other, other.sign()))
+    }
+
+    /// Subtracts `other` from self, clamping the result to `MIN` or `MAX`
+    /// instead of overflowing.
+    pub fn saturating_sub(self, other: Self) -> Self {
+        self.clone()
+            .checked_sub(other.clone())
+            .unwrap_or_else(|_| self.saturate_sum(&other, other.sign().flip()))
+    }
+
+    /// Computes `self * other / divisor`, rounding towards zero and clamping
+    /// the result to `MIN` or `MAX` instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero.
+    pub fn saturating_mul_div_down(self, other: Self, divisor: Self) -> Self {
+        if divisor.is_zero() {
+            panic!("Cannot divide by zero: {self} * {other} / {divisor}");
+        }
+        self.clone()
+            .checked_mul_div_down(other.clone(), divisor.clone())
+            .unwrap_or_else(|_| self.saturate_product(&other, &divisor))
+    }
+
+    /// Computes `self * other / divisor`, rounding away from zero and clamping
+    /// the result to `MIN` or `MAX` instead of overflowing.
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero.
+    pub fn saturating_mul_div_up(self, other: Self, divisor: Self) -> Self {
+        if divisor.is_zero() {
+            panic!("Cannot divide by zero: {self} * {other} / {divisor}");
+        }
+        self.clone()
+            .checked_mul_div_up(other.clone(), divisor.clone())
+            .unwrap_or_else(|_| self.saturate_product(&other, &divisor))
+    }
+
+    pub fn saturating_mul_down(self, other: Self) -> Self {
+        let one = self.one();
+        self.saturating_mul_div_down(other, one)
+    }
+
+    pub fn saturating_mul_up(self, other: Self) -> Self {
+        let one = self.one();
+        self.saturating_mul_div_up(other, one)
+    }
+
+    pub fn saturating_div_down(self, other: Self) -> Self {
+        let one = self.one();
+        self.saturating_mul_div_down(one, other)
+    }
+
+    pub fn saturating_div_up(self, other: Self) -> Self {
+        let one = self.one();
+        self.saturating_mul_div_up(one, other)
+    }
+
+    // Wrapping Arithmetic //
+
+    /// Adds `other` to self, wrapping around at the boundary of `T` like an
+    /// `unchecked` addition in Solidity.
+    pub fn wrapping_add(self, other: Self) -> Self {
+        let sign = other.sign();
+        self.wrapping_add_signed(other, sign)
+    }
+
+    /// Subtracts `other` from self, wrapping around at the boundary of `T`
+    /// like an `unchecked` subtraction in Solidity.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        let sign = other.sign().flip();
+        self.wrapping_add_signed(other, sign)
+    }
+
+    /// Computes `self * other / divisor` like `unchecked { x * y / d }` in
+    /// Solidity, i.e., the product wraps around at the boundary of `T` before
+    /// it's divided, rounding towards zero.
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero.
+    pub fn wrapping_mul_div_down(self, other: Self, divisor: Self) -> Self {
+        let (sign, abs, _) = self.wrapping_mul_div_abs(&other, &divisor);
+        Self::wrap(sign, abs)
+    }
+
+    /// Computes `self * other / divisor` with a wrapping product like
+    /// [`FixedPoint::wrapping_mul_div_down`], but rounds away from zero.
+    ///
+    /// # Panics
+    ///
+    /// If the divisor is zero.
+    pub fn wrapping_mul_div_up(self, other: Self, divisor: Self) -> Self {
+        let (sign, mut abs, rem) = self.wrapping_mul_div_abs(&other, &divisor);
+        if !rem.is_zero() {
+            abs = abs + Wide::<T>::one();
+        }
+        Self::wrap(sign, abs)
+    }
+
+    pub fn wrapping_mul_down(self, other: Self) -> Self {
+        let one = self.one();
+        self.wrapping_mul_div_down(other, one)
+    }
+
+    pub fn wrapping_mul_up(self, other: Self) -> Self {
+        let one = self.one();
+        self.wrapping_mul_div_up(other, one)
+    }
+
+    pub fn wrapping_div_down(self, other: Self) -> Self {
+        let one = self.one();
+        self.wrapping_mul_div_down(one, other)
+    }
+
+    pub fn wrapping_div_up(self, other: Self) -> Self {
+        let one = self.one();
+        self.wrapping_mul_div_up(one, other)
+    }
+
+    // Integer Division //
+
+    /// Computes the integer quotient of `self / other` and the remainder, which
+    /// has the sign of self, so that `self = quotient * other + remainder` as
+    /// for the primitive integers' `/` and `%`. Returns an error if `other` is
+    /// zero or the quotient overflows `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    /// use ethers::types::I256;
+    ///
+    /// // 2.5 units of 0.75 fit 3 times with 0.25 left over.
+    /// let (quotient, remainder) = fixed_i256!(2.5e18).checked_div_rem(fixed_i256!(0.75e18)).unwrap();
+    /// assert_eq!(quotient, I256::from(3));
+    /// assert_eq!(remainder, fixed_i256!(0.25e18));
+    /// ```
+    pub fn checked_div_rem(self, other: Self) -> Result<(T, Self)> {
+        let (sign, quotient, remainder) = self.clone().div_rem_abs(other.clone(), false)?;
+        let quotient = T::Unsigned::try_from_wide(quotient)
+            .and_then(|quotient| FixedPoint::<T, 0>::from_sign_and_abs(sign, quotient).ok())
+            .ok_or_else(|| FixedPointError::overflow(Operation::Div, &[&self, &other]))?;
+        Ok((quotient.raw(), remainder))
+    }
+
+    /// Computes the integer quotient of `self / other` rounded so that the
+    /// remainder is non-negative, as in [`i128::div_euclid`]. Returns an error
+    /// if `other` is zero or the quotient overflows `T` with self's decimals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// let x = fixed_i256!(-2.5e18);
+    /// assert_eq!(x.checked_div_euclid(fixed_i256!(1e18)), Ok(fixed_i256!(-3e18)));
+    /// assert_eq!(x.checked_div_euclid(fixed_i256!(-1e18)), Ok(fixed_i256!(3e18)));
+    /// ```
+    pub fn checked_div_euclid(self, other: Self) -> Result<Self> {
+        let overflow = || FixedPointError::overflow(Operation::Div, &[&self, &other]);
+        let (sign, quotient, _) = self.clone().div_rem_abs(other.clone(), true)?;
+        quotient
+            .checked_mul(Wide::<T>::exp10(D.into()))
+            .and_then(T::Unsigned::try_from_wide)
+            .and_then(|quotient| Self::from_sign_and_abs(sign, quotient).ok())
+            .ok_or_else(overflow)
+    }
+
+    /// Computes the non-negative remainder of `self / other`, as in
+    /// [`i128::rem_euclid`]. Returns an error if `other` is zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// let x = fixed_i256!(-2.5e18);
+    /// assert_eq!(x.checked_rem_euclid(fixed_i256!(1e18)), Ok(fixed_i256!(0.5e18)));
+    /// assert_eq!(x.checked_rem_euclid(fixed_i256!(-1e18)), Ok(fixed_i256!(0.5e18)));
+    /// ```
+    pub fn checked_rem_euclid(self, other: Self) -> Result<Self> {
+        self.div_rem_abs(other, true)
+            .map(|(_, _, remainder)| remainder)
+    }
+
+    /// Computes the remainder of `self / other`, which has the sign of self.
+    /// Returns an error if `other` is zero.
+    pub fn checked_rem(self, other: Self) -> Result<Self> {
+        self.div_rem_abs(other, false)
+            .map(|(_, _, remainder)| remainder)
+    }
+
+    /// Divides self by an integer, e.g., to split an amount into `n` equal
+    /// parts, rounding with the given mode. Returns an error if `n` is zero or
+    /// the result overflows `T`, e.g., when dividing `MIN` by `-1`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_u256, RoundingMode};
+    ///
+    /// let amount = fixed_u256!(10e18);
+    /// assert_eq!(
+    ///     amount.checked_div_int(3_u128, RoundingMode::Floor),
+    ///     Ok(fixed_u256!(3.333333333333333333e18))
+    /// );
+    /// assert!(amount.checked_div_int(0_u128, RoundingMode::Floor).is_err());
+    /// ```
+    pub fn checked_div_int<V: Into<T>>(self, n: V, rounding: RoundingMode) -> Result<Self> {
+        let n: T = n.into();
+        if n.is_zero() {
+            return Err(FixedPointError::division_by_zero(
+                Operation::Div,
+                &[self.to_string(), format!("{n:?}")],
+            ));
+        }
+        let sign = self.sign().flip_if(n.is_negative());
+        let divisor = Wide::<T>::from(n.clone().unsigned_abs());
+        let (quotient, remainder) =
+            Wide::<T>::from(self.raw().unsigned_abs()).div_mod(divisor.clone());
+        let abs = rounding.round(sign, quotient, remainder, divisor);
+        T::Unsigned::try_from_wide(abs)
+            .and_then(|abs| Self::from_sign_and_abs(sign, abs).ok())
+            .ok_or_else(|| {
+                FixedPointError::overflow(Operation::Div, &[self.to_string(), format!("{n:?}")])
+            })
+    }
+
+    /// Computes the integer quotient of `self / other`.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is zero or the quotient overflows `T`. See
+    /// [`FixedPoint::checked_div_rem`] for a non-panicking version.
+    pub fn div_rem(self, other: Self) -> (T, Self) {
+        self.checked_div_rem(other).unwrap()
+    }
+
+    /// Computes the integer quotient of `self / other` rounded so that the
+    /// remainder is non-negative.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is zero or the quotient overflows `T`. See
+    /// [`FixedPoint::checked_div_euclid`] for a non-panicking version.
+    pub fn div_euclid(self, other: Self) -> Self {
+        self.checked_div_euclid(other).unwrap()
+    }
+
+    /// Computes the non-negative remainder of `self / other`.
+    ///
+    /// # Panics
+    ///
+    /// If `other` is zero. See [`FixedPoint::checked_rem_euclid`] for a
+    /// non-panicking version.
+    pub fn rem_euclid(self, other: Self) -> Self {
+        self.checked_rem_euclid(other).unwrap()
+    }
+
+    /// Divides self by an integer, rounding with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// If `n` is zero or the result overflows `T`. See
+    /// [`FixedPoint::checked_div_int`] for a non-panicking version.
+    pub fn div_int<V: Into<T>>(self, n: V, rounding: RoundingMode) -> Self {
+        self.checked_div_int(n, rounding).unwrap()
+    }
+
+    // Rounding //
+
+    /// Rounds self to a multiple of `step`, e.g., a tick size or `0.01`, with
+    /// the given mode, returning an error if `step` is zero or the result
+    /// overflows `T`. The sign of `step` is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_i256, RoundingMode};
+    ///
+    /// let price = fixed_i256!(-1.2345e18);
+    /// let tick = fixed_i256!(0.01e18);
+    /// let rounded = price.checked_quantize(tick, RoundingMode::Floor).unwrap();
+    /// assert_eq!(rounded, fixed_i256!(-1.24e18));
+    /// ```
+    pub fn checked_quantize(self, step: Self, rounding: RoundingMode) -> Result<Self> {
+        if step.is_zero() {
+            return Err(FixedPointError::division_by_zero(
+                Operation::Quantize,
+                &[self, step],
+            ));
+        }
+        let overflow = || FixedPointError::overflow(Operation::Quantize, &[&self, &step]);
+        let step_abs = Wide::<T>::from(step.raw().unsigned_abs());
+        let (quotient, remainder) =
+            Wide::<T>::from(self.raw().unsigned_abs()).div_mod(step_abs.clone());
+        let abs = rounding.round(self.sign(), quotient, remainder, step_abs.clone()) * step_abs;
+        T::Unsigned::try_from_wide(abs)
+            .and_then(|abs| Self::from_sign_and_abs(self.sign(), abs).ok())
+            .ok_or_else(overflow)
+    }
+
+    /// Rounds self to an integer with the given mode, returning an error if
+    /// the result overflows `T`, e.g., when rounding `MIN` down.
+    pub fn checked_round(self, rounding: RoundingMode) -> Result<Self> {
+        let one = self.one();
+        self.checked_quantize(one, rounding)
+    }
+
+    /// Rounds self to a multiple of `step` with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// If `step` is zero or the result overflows `T`. See
+    /// [`FixedPoint::checked_quantize`] for a non-panicking version.
+    pub fn quantize(self, step: Self, rounding: RoundingMode) -> Self {
+        self.checked_quantize(step, rounding).unwrap()
+    }
+
+    /// Rounds self to an integer with the given mode.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`. See [`FixedPoint::checked_round`] for a
+    /// non-panicking version.
+    pub fn round(self, rounding: RoundingMode) -> Self {
+        self.checked_round(rounding).unwrap()
+    }
+
+    /// Returns the largest integer less than or equal to self.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`.
+    pub fn floor(self) -> Self {
+        self.round(RoundingMode::Floor)
+    }
+
+    /// Returns the smallest integer greater than or equal to self.
+    ///
+    /// # Panics
+    ///
+    /// If the result overflows `T`.
+    pub fn ceil(self) -> Self {
+        self.round(RoundingMode::Ceil)
+    }
+
+    /// Returns the integer part of self, i.e., self rounded towards zero.
+    pub fn trunc(self) -> Self {
+        // Truncating can't increase the absolute value, so it can't overflow.
+        self.round(RoundingMode::TowardZero)
+    }
+
+    /// Returns the fractional part of self, which has the same sign as self,
+    /// e.g., `-0.5` for `-1.5`.
+    pub fn fract(self) -> Self {
+        let trunc = self.clone().trunc();
+        self - trunc
+    }
+
+    /// Converts the integer part of self into an integer type, e.g., `u64`,
+    /// returning an error if it's out of the type's range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// assert_eq!(fixed_i256!(-2.9e18).to_integer::<i64>(), Ok(-2));
+    /// assert!(fixed_i256!(-1e18).to_integer::<u64>().is_err());
+    /// ```
+    pub fn to_integer<I: TryFrom<T>>(&self) -> Result<I> {
+        // Reducing the scale truncates toward zero and can't overflow.
+        let int = self.clone().rescale::<0>(RoundingMode::TowardZero)?.raw();
+        I::try_from(int).map_err(|_| FixedPointError::OutOfRange {
+            value: self.to_string(),
+            target: core::any::type_name::<I>(),
+        })
+    }
+
+    // Integer Powers //
+
+    /// Raises self to an integer power by repeated squaring, as in MakerDAO's
+    /// `rpow`.
+    ///
+    /// Every intermediate product is rounded in the direction the result is
+    /// rounded, so with a directed mode the result is a bound on the true
+    /// power, e.g., it's never above it with [`RoundingMode::Floor`], and it's
+    /// exact whenever the products are. The errors of the products compound,
+    /// so large powers can be off by more than a unit in the last place,
+    /// including with the modes that round to the nearest value. Negative
+    /// exponents divide one by the power, which is rounded in the opposite
+    /// direction.
+    ///
+    /// Returns an error if self is zero and `n` is negative, if a negative
+    /// power rounds to zero, or if the result or an intermediate product
+    /// overflows `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_i256, RoundingMode};
+    ///
+    /// let x = fixed_i256!(-1.5e18);
+    /// assert_eq!(x.pow_int(2, RoundingMode::Floor), Ok(fixed_i256!(2.25e18)));
+    /// assert_eq!(x.pow_int(3, RoundingMode::Floor), Ok(fixed_i256!(-3.375e18)));
+    ///
+    /// let x = fixed_i256!(3e18);
+    /// assert_eq!(x.pow_int(-1, RoundingMode::Floor), Ok(fixed_i256!(0.333333333333333333e18)));
+    /// assert_eq!(x.pow_int(-1, RoundingMode::Ceil), Ok(fixed_i256!(0.333333333333333334e18)));
+    /// ```
+    pub fn pow_int(self, n: i32, rounding: RoundingMode) -> Result<Self> {
+        let overflow =
+            || FixedPointError::overflow(Operation::Pow, &[self.to_string(), n.to_string()]);
+        let sign = FixedPointSign::from(!self.is_negative() || n % 2 == 0);
+        let rounding = rounding.for_sign(sign);
+        let scale = Wide::<T>::exp10(self.decimals().into());
+
+        // Products are kept within the range of `T::Unsigned` so that the next
+        // product can't overflow the wide type.
+        let product_rounding = if n < 0 { rounding.opposite() } else { rounding };
+        let mul = |lhs: Wide<T>, rhs: Wide<T>| {
+            let (quotient, remainder) = (lhs * rhs).div_mod(scale.clone());
+            let product = product_rounding.round(
+                FixedPointSign::Positive,
+                quotient,
+                remainder,
+                scale.clone(),
+            );
+            T::Unsigned::try_from_wide(product.clone())
+                .map(|_| product)
+                .ok_or_else(overflow)
+        };
+        let mut base = Wide::<T>::from(self.raw().unsigned_abs());
+        let mut power = scale.clone();
+        let mut exponent = n.unsigned_abs();
+        while exponent > 0 {
+            if exponent % 2 == 1 {
+                power = mul(power, base.clone())?;
+            }
+            exponent /= 2;
+            if exponent > 0 {
+                base = mul(base.clone(), base)?;
+            }
+        }
+
+        if n < 0 {
+            if power.is_zero() {
+                return Err(FixedPointError::division_by_zero(
+                    Operation::Pow,
+                    &[self.to_string(), n.to_string()],
+                ));
+            }
+            let (quotient, remainder) = (scale.clone() * scale).div_mod(power.clone());
+            power = rounding.round(FixedPointSign::Positive, quotient, remainder, power);
+        }
+        T::Unsigned::try_from_wide(power)
+            .and_then(|abs| Self::from_sign_and_abs(sign, abs).ok())
+            .ok_or_else(overflow)
+    }
+
+    // Roots //
+
+    /// Computes the `n`th root of self, rounding with the given mode. The
+    /// root is computed exactly from the raw value scaled by `self.decimals()`
+    /// in the extended type, so it's correct to the last decimal place.
+    ///
+    /// Returns an error if self is negative, `n` is zero, or the raw value
+    /// times `10^(decimals * (n - 1))` doesn't fit in the extended type.
+    /// Square roots always fit, but higher roots are limited, e.g., to
+    /// `n <= 13` for `U256` with 18 decimals.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_u256, RoundingMode};
+    ///
+    /// let x = fixed_u256!(2e18);
+    /// assert_eq!(x.nth_root(2, RoundingMode::Floor), Ok(fixed_u256!(1.414213562373095048e18)));
+    /// assert_eq!(x.nth_root(2, RoundingMode::Ceil), Ok(fixed_u256!(1.414213562373095049e18)));
+    /// assert_eq!(fixed_u256!(27e18).nth_root(3, RoundingMode::Ceil), Ok(fixed_u256!(3e18)));
+    /// ```
+    pub fn nth_root(self, n: u32, rounding: RoundingMode) -> Result<Self> {
+        if n == 0 {
+            return Err(FixedPointError::division_by_zero(
+                Operation::Root(n),
+                &[self],
+            ));
+        }
+        if self.is_negative() {
+            return Err(FixedPointError::NegativeRoot {
+                value: self.to_string(),
+            });
+        }
+        let overflow = || FixedPointError::overflow(Operation::Root(n), &[&self]);
+
+        // The root of `raw / 10^decimals` scaled by `10^decimals` is the root of
+        // `raw * 10^(decimals * (n - 1))`.
+        let scale = usize::from(self.decimals()) * (n as usize - 1);
+        let radicand = precise::checked_exp10::<Extended<T>>(scale)
+            .and_then(|power| power.checked_mul(Extended::<T>::from(self.raw().unsigned_abs())))
+            .ok_or_else(overflow)?;
+        let root = Self::integer_root(&radicand, n);
+
+        // The remainder is in quarters of the distance to the next integer,
+        // which is enough to say which side of the midpoint the root is on.
+        // Roots are never exactly halfway since `(2 * root + 1)^n` is odd.
+        let remainder = match rounding {
+            _ if Self::checked_pow_int(root.clone(), n).as_ref() == Some(&radicand) => 0,
+            RoundingMode::HalfUp
+            | RoundingMode::HalfDown
+            | RoundingMode::HalfEven
+            | RoundingMode::HalfCeil
+            | RoundingMode::HalfFloor => {
+                let two = Extended::<T>::from_u64(2);
+                let midpoint = Self::checked_pow_int(root.clone() * two + Extended::<T>::one(), n);
+                let radicand = radicand.checked_mul(Extended::<T>::pow2(n as usize));
+                match (midpoint, radicand) {
+                    (Some(midpoint), Some(radicand)) if radicand > midpoint => 3,
+                    (Some(_), Some(_)) => 1,
+                    _ => return Err(overflow()),
+                }
+            }
+            _ => 1,
+        };
+        let abs = rounding.round(
+            FixedPointSign::Positive,
+            root,
+            Extended::<T>::from_u64(remainder),
+            Extended::<T>::from_u64(4),
+        );
+        T::Unsigned::try_from_extended(abs)
+            .and_then(|abs| Self::from_sign_and_abs(FixedPointSign::Positive, abs).ok())
+            .ok_or_else(overflow)
+    }
+
+    /// Computes the square root of self, rounding with the given mode. See
+    /// [`FixedPoint::nth_root`].
+    pub fn sqrt(self, rounding: RoundingMode) -> Result<Self> {
+        self.nth_root(2, rounding)
+    }
+
+    /// Alias for [`FixedPoint::sqrt`] with [`RoundingMode::Floor`].
+    pub fn sqrt_down(self) -> Result<Self> {
+        self.sqrt(RoundingMode::Floor)
+    }
+
+    /// Alias for [`FixedPoint::sqrt`] with [`RoundingMode::Ceil`].
+    pub fn sqrt_up(self) -> Result<Self> {
+        self.sqrt(RoundingMode::Ceil)
+    }
+
+    /// Computes the cube root of self, rounding with the given mode. See
+    /// [`FixedPoint::nth_root`].
+    pub fn cbrt(self, rounding: RoundingMode) -> Result<Self> {
+        self.nth_root(3, rounding)
+    }
+
+    // Logarithms & Exponentials //
+
+    /// Computes the natural logarithm of self with [`Accuracy::Precise`]. See
+    /// [`FixedPoint::ln_with`] for the errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// assert_eq!(fixed_i256!(1e18).ln(), Ok(fixed_i256!(0)));
+    /// assert_eq!(fixed_i256!(2e18).ln(), Ok(fixed_i256!(0.693147180559945309e18)));
+    /// assert_eq!(fixed_i256!(0.1e18).ln(), Ok(fixed_i256!(-2.302585092994045684e18)));
+    /// ```
+    pub fn ln(self) -> Result<Self> {
+        self.ln_with(Accuracy::Precise)
+    }
+
+    /// Computes the natural logarithm of self with the given accuracy.
+    ///
+    /// Returns an error if self isn't positive or the result doesn't fit in
+    /// `T`, e.g., if it's negative and `T` is unsigned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_i256, Accuracy};
+    ///
+    /// let x = fixed_i256!(0.5e18);
+    /// assert_eq!(
+    ///     x.ln_with(Accuracy::SolidityCompatible),
+    ///     Ok(fixed_i256!(-0.693147180559945310e18))
+    /// );
+    /// assert_eq!(
+    ///     x.ln_with(Accuracy::Precise),
+    ///     Ok(fixed_i256!(-0.693147180559945309e18))
+    /// );
+    /// ```
+    pub fn ln_with(self, accuracy: Accuracy) -> Result<Self> {
+        match accuracy {
+            Accuracy::SolidityCompatible => self.solidity_compatible(ln_int256),
+            Accuracy::Precise => {
+                let overflow =
+                    || FixedPointError::overflow(Operation::Log, &[self.to_string(), "e".into()]);
+                let precision = self.precision();
+                if !precise::fits::<Extended<T>>(precision) {
+                    return Err(overflow());
+                }
+                let (sign, ln) = self.precise_ln(precision)?;
+                Self::from_ratio(sign, ln, Extended::<T>::exp10(GUARD_DIGITS), overflow)
+            }
+        }
+    }
+
+    /// Computes the base 2 logarithm of self with [`Accuracy::Precise`]. See
+    /// [`FixedPoint::ln_with`] for the errors.
+    pub fn log2(self) -> Result<Self> {
+        self.log_constant(precise::ln2::<Extended<T>>, "2")
+    }
+
+    /// Computes the base 10 logarithm of self with [`Accuracy::Precise`]. See
+    /// [`FixedPoint::ln_with`] for the errors.
+    pub fn log10(self) -> Result<Self> {
+        self.log_constant(precise::ln10::<Extended<T>>, "10")
+    }
+
+    /// Computes the logarithm of self in the given base with
+    /// [`Accuracy::Precise`].
+    ///
+    /// Returns an error if self or the base isn't positive, the base is 1, or
+    /// the result doesn't fit in `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_u256;
+    ///
+    /// assert_eq!(fixed_u256!(1_000e18).log(fixed_u256!(10e18)), Ok(fixed_u256!(3e18)));
+    /// assert!(fixed_u256!(2e18).log(fixed_u256!(1e18)).is_err());
+    /// ```
+    pub fn log(self, base: Self) -> Result<Self> {
+        let overflow = || FixedPointError::overflow(Operation::Log, &[&self, &base]);
+        let precision = self.precision();
+        if !precise::fits::<Extended<T>>(precision) {
+            return Err(overflow());
+        }
+        let (_, ln_base) = base.precise_ln(precision)?;
+        if ln_base.is_zero() {
+            return Err(FixedPointError::division_by_zero(
+                Operation::Log,
+                &[self, base],
+            ));
+        }
+        let (_, ln) = self.precise_ln(precision)?;
+
+        // The quotient is only as precise as its divisor, so the logarithms
+        // are recomputed with enough digits for the divisor to have
+        // `precision` significant digits beyond the integer digits of the
+        // quotient.
+        let digits = |value: &Extended<T>| value.to_string().len();
+        let precision = precision
+            + (precision + 1).saturating_sub(digits(&ln_base))
+            + digits(&ln).saturating_sub(digits(&ln_base));
+        if !precise::fits::<Extended<T>>(precision) {
+            return Err(overflow());
+        }
+        let (base_sign, ln_base) = base.precise_ln(precision)?;
+        let (sign, ln) = self.precise_ln(precision)?;
+        Self::from_ratio(
+            sign.flip_if(base_sign.is_negative()),
+            ln * Extended::<T>::exp10(self.decimals().into()),
+            ln_base,
+            overflow,
+        )
+    }
+
+    /// Computes `e^self` with [`Accuracy::Precise`]. See
+    /// [`FixedPoint::exp_with`] for the errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// assert_eq!(fixed_i256!(0).exp(), Ok(fixed_i256!(1e18)));
+    /// assert_eq!(fixed_i256!(1e18).exp(), Ok(fixed_i256!(2.718281828459045235e18)));
+    /// assert_eq!(fixed_i256!(-50e18).exp(), Ok(fixed_i256!(0)));
+    /// ```
+    pub fn exp(self) -> Result<Self> {
+        self.exp_with(Accuracy::Precise)
+    }
+
+    /// Computes `e^self` with the given accuracy.
+    ///
+    /// Returns an error if the result doesn't fit in `T`. With
+    /// [`Accuracy::Precise`], it's also an error if the result has more
+    /// digits than the extended intermediates can hold, which is only possible
+    /// for 512-bit types. Results too small to represent are zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_i256, Accuracy};
+    ///
+    /// // e^-42 is about 5.7e-19, which rounds to 1e-18.
+    /// let x = fixed_i256!(-42e18);
+    /// assert_eq!(x.exp_with(Accuracy::SolidityCompatible), Ok(fixed_i256!(0)));
+    /// assert_eq!(x.exp_with(Accuracy::Precise), Ok(fixed_i256!(1)));
+    /// ```
+    pub fn exp_with(self, accuracy: Accuracy) -> Result<Self> {
+        match accuracy {
+            Accuracy::SolidityCompatible => self.solidity_compatible(exp_int256),
+            Accuracy::Precise => {
+                let decimals = usize::from(self.decimals());
+                let abs = Extended::<T>::from(self.raw().unsigned_abs());
+                self.precise_exp(
+                    |precision| {
+                        Some((
+                            self.sign(),
+                            abs.clone() * Extended::<T>::exp10(precision - decimals),
+                        ))
+                    },
+                    || FixedPointError::ExpOverflow {
+                        value: self.to_string(),
+                    },
+                )
+            }
+        }
+    }
+
+    /// Computes `2^self` with [`Accuracy::Precise`]. Integer powers are exact
+    /// before they're rounded to `self.decimals()` places. See
+    /// [`FixedPoint::exp_with`] for the errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// assert_eq!(fixed_i256!(10e18).exp2(), Ok(fixed_i256!(1_024e18)));
+    /// assert_eq!(fixed_i256!(-2e18).exp2(), Ok(fixed_i256!(0.25e18)));
+    /// assert_eq!(fixed_i256!(0.5e18).exp2(), Ok(fixed_i256!(1.414213562373095049e18)));
+    /// ```
+    pub fn exp2(self) -> Result<Self> {
+        let abs = Extended::<T>::from(self.raw().unsigned_abs());
+        let scale = Extended::<T>::exp10(self.decimals().into());
+        let overflow = || FixedPointError::ExpOverflow {
+            value: self.to_string(),
+        };
+        let (integer, remainder) = abs.clone().div_mod(scale.clone());
+        if remainder.is_zero() && integer.bits() <= 10 {
+            let power = Extended::<T>::pow2(integer.low_u64() as usize);
+            return match self.sign() {
+                FixedPointSign::Positive => Self::from_ratio(
+                    FixedPointSign::Positive,
+                    power.checked_mul(scale).ok_or_else(overflow)?,
+                    Extended::<T>::one(),
+                    overflow,
+                ),
+                FixedPointSign::Negative => {
+                    Self::from_ratio(FixedPointSign::Positive, scale, power, overflow)
+                }
+            };
+        }
+
+        // `2^self = e^(self * ln(2))`, where `ln(2)` has enough digits for the
+        // product to have the requested precision.
+        let decimals = usize::from(self.decimals());
+        let digits = abs.to_string().len();
+        self.precise_exp(
+            |precision| {
+                let ln2 = precise::ln2::<Extended<T>>(precision + digits);
+                let z = abs.clone().checked_mul(ln2)?;
+                Some((
+                    self.sign(),
+                    z.div_mod(Extended::<T>::exp10(digits + decimals)).0,
+                ))
+            },
+            overflow,
+        )
+    }
+
+    /// Raises self to the power of `y` with the given accuracy.
+    ///
+    /// With [`Accuracy::SolidityCompatible`], this is
+    /// [`FixedPoint::checked_pow`]. With [`Accuracy::Precise`], `0^y` is `0`
+    /// for positive `y`, and self can be negative if `y` is a fraction with an
+    /// odd denominator in lowest terms, e.g., an integer or `0.2`, in which
+    /// case the result is negative if `y`'s numerator is odd. It returns an
+    /// error if self is zero and `y` is negative or the result overflows as in
+    /// [`FixedPoint::exp_with`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_u256, Accuracy};
+    ///
+    /// let x = fixed_u256!(2e18);
+    /// let y = fixed_u256!(0.5e18);
+    /// assert_eq!(
+    ///     x.pow_with(y, Accuracy::SolidityCompatible),
+    ///     Ok(fixed_u256!(1.414213562373095047e18))
+    /// );
+    /// assert_eq!(
+    ///     x.pow_with(y, Accuracy::Precise),
+    ///     Ok(fixed_u256!(1.414213562373095049e18))
+    /// );
+    /// ```
+    pub fn pow_with(self, y: Self, accuracy: Accuracy) -> Result<Self> {
+        match accuracy {
+            Accuracy::SolidityCompatible => self.checked_pow(y),
+            Accuracy::Precise => self.precise_pow(y),
+        }
+    }
+
+    /// Returns a lower bound of self raised to the power of `y` computed from
+    /// [`FixedPoint::checked_pow`].
+    ///
+    /// Assuming the logarithm of FixedPointMath.sol is within `2e-18` of the
+    /// true value and its exponential has a relative error of at most `1e-18`
+    /// plus the `1e-18` of truncating the result, `checked_pow` has a relative
+    /// error of at most `(4|y| + 4)e-18` plus `1e-18`. That error is subtracted
+    /// from its result, and self and `y` are rounded to 18 decimals in the
+    /// direction that lowers the result. The assumed errors are the measured
+    /// errors of FixedPointMath.sol with some margin rather than proven bounds,
+    /// so the result is a lower bound as far as they've been tested against a
+    /// high-precision reference. See [`FixedPoint::checked_pow`] for the
+    /// errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_u256;
+    ///
+    /// let x = fixed_u256!(2e18);
+    /// let y = fixed_u256!(0.5e18);
+    /// assert_eq!(x.pow(y), Ok(fixed_u256!(1.414213562373095047e18)));
+    /// assert_eq!(x.pow_down(y), Ok(fixed_u256!(1.414213562373095037e18)));
+    /// assert_eq!(x.pow_up(y), Ok(fixed_u256!(1.414213562373095057e18)));
+    /// ```
+    pub fn pow_down(self, y: Self) -> Result<Self> {
+        self.pow_bound(y, RoundingMode::Floor)
+    }
+
+    /// Returns an upper bound of self raised to the power of `y` computed from
+    /// [`FixedPoint::checked_pow`], see [`FixedPoint::pow_down`] for the error
+    /// bound. Returns an error if the relative error bound exceeds `100%`,
+    /// e.g., for very large `y`, since it no longer bounds the result.
+    pub fn pow_up(self, y: Self) -> Result<Self> {
+        self.pow_bound(y, RoundingMode::Ceil)
+    }
+
+    /// Raises self to the power of `numerator / denominator` with
+    /// [`Accuracy::Precise`].
+    ///
+    /// Self can be negative if the denominator is odd in lowest terms, in which
+    /// case the result is negative if the numerator is also odd. Returns an
+    /// error if the denominator is zero or the result doesn't fit in `T`. See
+    /// [`FixedPoint::pow_with`] for the other errors.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::fixed_i256;
+    ///
+    /// assert_eq!(fixed_i256!(-8e18).pow_ratio(1, 3), Ok(fixed_i256!(-2e18)));
+    /// assert_eq!(fixed_i256!(-8e18).pow_ratio(-2, 3), Ok(fixed_i256!(0.25e18)));
+    /// assert!(fixed_i256!(-8e18).pow_ratio(1, 2).is_err());
+    /// ```
+    pub fn pow_ratio(self, numerator: i32, denominator: u32) -> Result<Self> {
+        let exponent = format!("{numerator}/{denominator}");
+        let overflow =
+            || FixedPointError::overflow(Operation::Pow, &[self.to_string(), exponent.clone()]);
+        if denominator == 0 {
+            return Err(FixedPointError::division_by_zero(
+                Operation::Pow,
+                &[self.to_string(), exponent],
+            ));
+        }
+
+        // Reduce the ratio to lowest terms to find the sign of the result.
+        let (mut a, mut b) = (numerator.unsigned_abs(), denominator);
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        let (p, q) = (numerator.unsigned_abs() / a, denominator / a);
+        let sign = match self.sign() {
+            FixedPointSign::Negative if q % 2 == 0 => {
+                return Err(FixedPointError::NonRealPower {
+                    base: self.to_string(),
+                    exponent,
+                });
+            }
+            FixedPointSign::Negative if p % 2 == 1 => FixedPointSign::Negative,
+            _ => FixedPointSign::Positive,
+        };
+
+        let abs = Self::from_sign_and_abs(FixedPointSign::Positive, self.raw().unsigned_abs())
+            .map_err(|_| overflow())?;
+        if p == 0 {
+            return Ok(self.one());
+        }
+        if abs.is_zero() {
+            return match numerator {
+                0.. => Ok(abs),
+                _ => Err(FixedPointError::division_by_zero(
+                    Operation::Pow,
+                    &[self.to_string(), exponent],
+                )),
+            };
+        }
+
+        // The exponent is rounded to the guard digits of `Accuracy::Precise`
+        // beyond self's decimals, so it needn't fit in `T`.
+        let y_sign = FixedPointSign::from(numerator >= 0);
+        let y_decimals = self.precision();
+        let (quotient, remainder) = (Extended::<T>::from_u64(p.into())
+            * Extended::<T>::exp10(y_decimals))
+        .div_mod(Extended::<T>::from_u64(q.into()));
+        let y_abs = RoundingMode::HalfEven.round(
+            y_sign,
+            quotient,
+            remainder,
+            Extended::<T>::from_u64(q.into()),
+        );
+        let result = abs.precise_pow_abs(y_sign, y_abs, y_decimals, overflow)?;
+        Self::from_sign_and_abs(sign, result.raw().unsigned_abs()).map_err(|_| overflow())
+    }
+
+    // Helpers //
+
+    /// Applies a function of FixedPointMath.sol to self truncated to 18
+    /// decimals and scales the result back to self's decimals.
+    fn solidity_compatible(&self, f: fn(Int256) -> Result<Int256>) -> Result<Self> {
+        let x = Int256::try_from_fixed(self, RoundingMode::TowardZero)?;
+        f(x)?.try_into_fixed(RoundingMode::TowardZero)
+    }
+
+    /// The number of decimals that [`Accuracy::Precise`] computes logarithms
+    /// with before they're rounded to self's decimals.
+    fn precision(&self) -> usize {
+        usize::from(self.decimals()) + GUARD_DIGITS
+    }
+
+    /// Computes the sign and absolute value of the natural logarithm of self
+    /// scaled by `10^precision`. The precision must fit in the extended type,
+    /// see [`precise::fits`].
+    fn precise_ln(&self, precision: usize) -> Result<(FixedPointSign, Extended<T>)> {
+        if self.is_zero() || self.is_negative() {
+            return Err(FixedPointError::LnNonPositive {
+                value: self.to_string(),
+            });
+        }
+
+        // Normalize the raw value to a mantissa, `m`, in `[1, 10)` scaled by
+        // `10^precision`, where `self = m * 10^exponent` and `ln(self) = ln(m)
+        // + exponent * ln(10)`.
+        let abs = Extended::<T>::from(self.raw().unsigned_abs());
+        let digits = abs.to_string().len();
+        let mantissa = match (precision + 1).checked_sub(digits) {
+            Some(shift) => abs * Extended::<T>::exp10(shift),
+            None => {
+                let divisor = Extended::<T>::exp10(digits - precision - 1);
+                let (quotient, remainder) = abs.div_mod(divisor.clone());
+                RoundingMode::HalfEven.round(FixedPointSign::Positive, quotient, remainder, divisor)
+            }
+        };
+        let mut exponent = digits as i64 - 1 - i64::from(self.decimals());
+        let mantissa = if mantissa == Extended::<T>::exp10(precision + 1) {
+            exponent += 1;
+            Extended::<T>::exp10(precision)
+        } else {
+            mantissa
+        };
+
+        let ln_mantissa = precise::ln_mantissa(mantissa, precision);
+        let ln_power = precise::ln10::<Extended<T>>(precision)
+            * Extended::<T>::from_u64(exponent.unsigned_abs());
+        Ok(match exponent {
+            0.. => (FixedPointSign::Positive, ln_mantissa + ln_power),
+            _ if ln_mantissa >= ln_power => (FixedPointSign::Positive, ln_mantissa - ln_power),
+            _ => (FixedPointSign::Negative, ln_power - ln_mantissa),
+        })
+    }
+
+    /// Divides the natural logarithm of self by the natural logarithm of a
+    /// base, computed by `ln_base` with the given precision.
+    fn log_constant(&self, ln_base: fn(usize) -> Extended<T>, base: &str) -> Result<Self> {
+        let overflow =
+            || FixedPointError::overflow(Operation::Log, &[self.to_string(), base.into()]);
+        let precision = self.precision();
+        if !precise::fits::<Extended<T>>(precision) {
+            return Err(overflow());
+        }
+        let (sign, ln) = self.precise_ln(precision)?;
+        Self::from_ratio(
+            sign,
+            ln * Extended::<T>::exp10(self.decimals().into()),
+            ln_base(precision),
+            overflow,
+        )
+    }
+
+    /// Computes `e^z` rounded to self's decimals, where `z(precision)` is the
+    /// sign and absolute value of `z` scaled by `10^precision`, within a few
+    /// units, or `None` if it overflows.
+    fn precise_exp(
+        &self,
+        z: impl Fn(usize) -> Option<(FixedPointSign, Extended<T>)>,
+        overflow: impl Fn() -> FixedPointError,
+    ) -> Result<Self> {
+        let decimals = usize::from(self.decimals());
+
+        // Estimate the power of 10 of the result, `e^z = e^r * 10^k` with `r`
+        // in `[0, ln(10))`, to bound it before choosing how many digits to
+        // compute it with.
+        let precision = self.precision();
+        let (sign, abs) = z(precision).ok_or_else(&overflow)?;
+        let k = abs.div_mod(precise::ln10(precision)).0;
+        let max_digits = match T::MAX {
+            Some(max) => Extended::<T>::from(max.unsigned_abs()).to_string().len(),
+            None => MAX_UNBOUNDED_DIGITS,
+        };
+        let precision = match sign {
+            FixedPointSign::Positive if k > Extended::<T>::from_u64(max_digits as u64) => {
+                return Err(overflow());
+            }
+            // The result is below `10^-k`, which rounds to zero.
+            FixedPointSign::Negative if k >= Extended::<T>::from_u64(decimals as u64 + 2) => {
+                return Ok(Self::zero());
+            }
+            // The raw result has up to `k + decimals + 1` digits.
+            FixedPointSign::Positive => precision + k.low_u64() as usize + 1,
+            FixedPointSign::Negative => precision,
+        };
+        if !precise::fits::<Extended<T>>(precision) {
+            return Err(overflow());
+        }
+
+        let (sign, abs) = z(precision).ok_or_else(&overflow)?;
+        let ln10 = precise::ln10::<Extended<T>>(precision);
+        let (k, r) = abs.div_mod(ln10.clone());
+        let k = k.low_u64() as i64;
+        let (k, r) = match sign {
+            FixedPointSign::Positive => (k, r),
+            FixedPointSign::Negative if r.is_zero() => (-k, r),
+            FixedPointSign::Negative => (-k - 1, ln10 - r),
+        };
+
+        // The raw result is `e^r * 10^(k + decimals)`.
+        let exp_r = precise::exp_remainder(r, precision);
+        let shift = k + decimals as i64;
+        let (numerator, denominator) = match usize::try_from(shift) {
+            Ok(shift) => (
+                exp_r * Extended::<T>::exp10(shift),
+                Extended::<T>::exp10(precision),
+            ),
+            Err(_) => (
+                exp_r,
+                Extended::<T>::exp10(precision + shift.unsigned_abs() as usize),
+            ),
+        };
+        Self::from_ratio(FixedPointSign::Positive, numerator, denominator, overflow)
+    }
+
+    /// Raises a negative self to the power of `y` by raising its absolute value
+    /// with `pow`, which is also passed the sign of the result. The result is
+    /// real if `y` is a fraction with an odd denominator in lowest terms, and
+    /// negative if its numerator is also odd.
+    fn negative_pow(
+        self,
+        y: Self,
+        pow: impl FnOnce(Self, Self, FixedPointSign) -> Result<Self>,
+    ) -> Result<Self> {
+        // `y = raw / (2^decimals * 5^decimals)`, so its denominator is odd in
+        // lowest terms if the raw value has at least `decimals` factors of 2,
+        // and dividing out the 5s doesn't change the numerator's parity.
+        let two = Wide::<T>::from_u64(2);
+        let mut numerator = Wide::<T>::from(y.raw().unsigned_abs());
+        for _ in 0..y.decimals() {
+            if numerator.is_odd() {
+                return Err(FixedPointError::NonRealPower {
+                    base: self.to_string(),
+                    exponent: y.to_string(),
+                });
+            }
+            numerator = numerator.div_mod(two.clone()).0;
+        }
+        let sign = FixedPointSign::from(!numerator.is_odd());
+
+        let abs = Self::from_sign_and_abs(FixedPointSign::Positive, self.raw().unsigned_abs())
+            .map_err(|_| FixedPointError::overflow(Operation::Pow, &[&self, &y]))?;
+        let result = pow(abs, y.clone(), sign)?;
+        Self::from_sign_and_abs(sign, result.raw().unsigned_abs())
+            .map_err(|_| FixedPointError::overflow(Operation::Pow, &[self, y]))
+    }
+
+    /// Raises self to the power of `y` with [`Accuracy::Precise`].
+    fn precise_pow(self, y: Self) -> Result<Self> {
+        if y.is_zero() {
+            return Ok(self.one());
+        }
+        if self.is_zero() {
+            return match y.sign() {
+                FixedPointSign::Positive => Ok(self),
+                FixedPointSign::Negative => Err(FixedPointError::division_by_zero(
+                    Operation::Pow,
+                    &[self, y],
+                )),
+            };
+        }
+        if self.is_negative() {
+            return self.negative_pow(y, |x, y, _| x.precise_pow(y));
+        }
+
+        let y_abs = Extended::<T>::from(y.raw().unsigned_abs());
+        self.precise_pow_abs(y.sign(), y_abs, usize::from(y.decimals()), || {
+            FixedPointError::overflow(Operation::Pow, &[&self, &y])
+        })
+    }
+
+    /// Raises a positive self to the power of `sign * abs / 10^decimals` with
+    /// [`Accuracy::Precise`], returning the given error if it overflows.
+    fn precise_pow_abs(
+        &self,
+        sign: FixedPointSign,
+        abs: Extended<T>,
+        decimals: usize,
+        overflow: impl Fn() -> FixedPointError,
+    ) -> Result<Self> {
+        // `x^y = e^(y * ln(x))`, where `ln(x)` has enough digits for the
+        // product to have the requested precision.
+        let y_digits = abs.to_string().len().saturating_sub(decimals);
+        self.precise_exp(
+            |precision| {
+                let precision = precision + y_digits;
+                if !precise::fits::<Extended<T>>(precision) {
+                    return None;
+                }
+                let (ln_sign, ln) = self.precise_ln(precision).ok()?;
+                let z = ln
+                    .checked_mul(abs.clone())?
+                    .div_mod(Extended::<T>::exp10(y_digits + decimals))
+                    .0;
+                Some((ln_sign.flip_if(sign == FixedPointSign::Negative), z))
+            },
+            overflow,
+        )
+    }
+
+    /// Computes the lower bound of self raised to the power of `y` for
+    /// [`RoundingMode::Floor`] and the upper bound for [`RoundingMode::Ceil`].
+    fn pow_bound(self, y: Self, rounding: RoundingMode) -> Result<Self> {
+        let overflow = || FixedPointError::overflow(Operation::Pow, &[&self, &y]);
+
+        // `x^-y = 1 / x^y`, so a bound of a negative power is the reciprocal of
+        // the opposite bound of the positive power.
+        if y.is_negative() {
+            let abs_y = Self::from_sign_and_abs(FixedPointSign::Positive, y.raw().unsigned_abs())
+                .map_err(|_| overflow())?;
+            let bound = self.clone().pow_bound(abs_y, rounding.opposite())?;
+            if bound.is_zero() {
+                return Err(FixedPointError::division_by_zero(
+                    Operation::Pow,
+                    &[self, y],
+                ));
+            }
+            return self.one().checked_div(bound, rounding);
+        }
+        if y.is_zero() {
+            return Ok(self.one());
+        }
+        if self.is_zero() {
+            return Ok(self);
+        }
+
+        // `x^y` increases with `x` for positive `y` and with `y` if `x >= 1`, so
+        // the inputs are rounded to 18 decimals in the direction of the bound.
+        let one = Int256::from(10_u128.pow(DEFAULT_DECIMALS.into()));
+        let x = Int256::try_from_fixed(&self, rounding)?;
+        let y_rounding = if x >= one {
+            rounding
+        } else {
+            rounding.opposite()
+        };
+        let (_, y) = Int256::try_from_fixed(&y, y_rounding)?.into_sign_and_abs();
+        let (_, result) = pow_int256(x, Int256::from_raw(y))?.into_sign_and_abs();
+        let (y, result) = (U1024::from(U512::from(y)), U1024::from(U512::from(result)));
+
+        // Assuming the measured error bounds hold, `y * ln(x)` is within
+        // `δ = |y| * LN_MAX_ERROR + 1e-18` of the true value after it's
+        // truncated, so the result is within a factor of `e^δ * (1 + ε)` of
+        // the true value plus `1e-18`, where `ε = EXP_MAX_RELATIVE_ERROR`.
+        // While `δ + ε <= 1/2`, that factor is within `2 * (δ + ε)` of 1 in
+        // either direction.
+        let scale = U1024::exp10(DEFAULT_DECIMALS.into());
+        let ceil_div = |numerator: U1024, denominator: U1024| {
+            let (quotient, remainder) = numerator.div_mod(denominator);
+            RoundingMode::Ceil.round(FixedPointSign::Positive, quotient, remainder, denominator)
+        };
+        let y_error = ceil_div(y * U1024::from(LN_MAX_ERROR), scale);
+        let relative_error = y_error + U1024::from(1 + EXP_MAX_RELATIVE_ERROR);
+        let max_error = ceil_div((result + 1) * relative_error * 2, scale) + U1024::one();
+        let bound = match rounding {
+            RoundingMode::Ceil if relative_error * 2 > scale => {
+                return Err(overflow());
+            }
+            RoundingMode::Ceil => result + max_error,
+            _ if result > max_error => result - max_error,
+            _ => U1024::zero(),
+        };
+        bound
+            .try_into_u512()
+            .and_then(|bound| U256::try_from(bound).ok())
+            .and_then(|bound| Int256::checked_from_sign_and_abs(FixedPointSign::Positive, bound))
+            .ok_or_else(overflow)?
+            .try_into_fixed(rounding)
+            .map_err(|_| overflow())
+    }
+
+    /// Divides self by `other`, returning the sign and absolute value of the
+    /// integer quotient and the remainder. The quotient is truncated, or
+    /// rounded so that the remainder is non-negative if `euclid` is true.
+    fn div_rem_abs(self, other: Self, euclid: bool) -> Result<(FixedPointSign, Wide<T>, Self)> {
+        if other.is_zero() {
+            let operation = if euclid {
+                Operation::Div
+            } else {
+                Operation::Rem
+            };
+            return Err(FixedPointError::division_by_zero(operation, &[self, other]));
+        }
+        let sign = self.sign().flip_if(other.is_negative());
+        let divisor = Wide::<T>::from(other.raw().unsigned_abs());
+        let (quotient, remainder) =
+            Wide::<T>::from(self.raw().unsigned_abs()).div_mod(divisor.clone());
+
+        // A negative dividend's remainder is moved up by the divisor, which
+        // moves the quotient away from zero.
+        let (quotient, remainder, remainder_sign) = match self.sign() {
+            FixedPointSign::Negative if euclid && !remainder.is_zero() => (
+                quotient + Wide::<T>::one(),
+                divisor - remainder,
+                FixedPointSign::Positive,
+            ),
+            sign => (quotient, remainder, sign),
+        };
+
+        // The remainder is less than the divisor, so it fits in `T`.
+        let remainder = Self::from_sign_and_abs(
+            remainder_sign,
+            T::Unsigned::try_from_wide(remainder).unwrap(),
+        )?;
+        Ok((sign, quotient, remainder))
+    }
+
+    /// Rounds `sign * numerator / denominator` half to even into a raw value,
+    /// returning the given error if it overflows.
+    fn from_ratio(
+        sign: FixedPointSign,
+        numerator: Extended<T>,
+        denominator: Extended<T>,
+        overflow: impl Fn() -> FixedPointError,
+    ) -> Result<Self> {
+        let (quotient, remainder) = numerator.div_mod(denominator.clone());
+        let abs = RoundingMode::HalfEven.round(sign, quotient, remainder, denominator);
+        let sign = if abs.is_zero() {
+            FixedPointSign::Positive
+        } else {
+            sign
+        };
+        let abs = T::Unsigned::try_from_extended(abs).ok_or_else(&overflow)?;
+        match Self::from_sign_and_abs(sign, abs) {
+            Ok(result) => Ok(result),
+            Err(FixedPointError::OutOfRange { .. }) => Err(overflow()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Computes the floor of the `n`th root of an integer with Newton's
+    /// method.
+    fn integer_root(radicand: &Extended<T>, n: u32) -> Extended<T> {
+        let zero = Extended::<T>::from_u64(0);
+        if radicand.is_zero() || n == 1 {
+            return radicand.clone();
+        }
+        let n_wide = Extended::<T>::from_u64(n.into());
+        let n_minus_one = Extended::<T>::from_u64((n - 1).into());
+
+        // Start from a power of 2 above the root so that the iterates decrease
+        // until they reach it.
+        let mut x = Extended::<T>::pow2(radicand.bits().div_ceil(n as usize));
+        loop {
+            // `x^(n - 1)` can only overflow if it's larger than the radicand.
+            let quotient = Self::checked_pow_int(x.clone(), n - 1)
+                .map_or(zero.clone(), |power| radicand.clone().div_mod(power).0);
+            let y = (x.clone() * n_minus_one.clone() + quotient)
+                .div_mod(n_wide.clone())
+                .0;
+            if y >= x {
+                return x;
+            }
+            x = y;
+        }
+    }
+
+    /// Raises a wide integer to a small power, returning `None` if the result
+    /// overflows.
+    fn checked_pow_int(base: Extended<T>, exponent: u32) -> Option<Extended<T>> {
+        (0..exponent).try_fold(Extended::<T>::one(), |power, _| {
+            power.checked_mul(base.clone())
+        })
+    }
+
+    /// Adds `other` with the given sign to self using wide magnitudes so that
+    /// unsigned subtraction can't underflow before the range check.
+    fn add_signed(self, other: Self, other_sign: FixedPointSign) -> Option<Self> {
+        let (sign, abs) = Self::sum_sign_and_abs(self.sign(), self, other_sign, other);
+        let abs = T::Unsigned::try_from_wide(abs)?;
+        Self::from_sign_and_abs(sign, abs).ok()
+    }
+
+    /// Adds `other` with the given sign to self, wrapping around at the
+    /// boundary of `T`.
+    fn wrapping_add_signed(self, other: Self, other_sign: FixedPointSign) -> Self {
+        let (sign, abs) = Self::sum_sign_and_abs(self.sign(), self, other_sign, other);
+        Self::wrap(sign, abs)
+    }
+
+    /// Computes the sign and absolute value of `lhs + rhs` where each operand's
+    /// absolute value is combined with the given sign.
+    fn sum_sign_and_abs(
+        lhs_sign: FixedPointSign,
+        lhs: Self,
+        rhs_sign: FixedPointSign,
+        rhs: Self,
+    ) -> (FixedPointSign, Wide<T>) {
+        let abs_lhs = Wide::<T>::from(lhs.raw().unsigned_abs());
+        let abs_rhs = Wide::<T>::from(rhs.raw().unsigned_abs());
+        if lhs_sign == rhs_sign {
+            (lhs_sign, abs_lhs + abs_rhs)
+        } else if abs_lhs >= abs_rhs {
+            (lhs_sign, abs_lhs - abs_rhs)
+        } else {
+            (rhs_sign, abs_rhs - abs_lhs)
+        }
+    }
+
+    /// Returns the bound that the sum of self and `other` with the given sign
+    /// overflowed.
+    fn saturate_sum(self, other: &Self, other_sign: FixedPointSign) -> Self {
+        let sign = if self.sign() == other_sign || self.unsigned_abs() >= other.unsigned_abs() {
+            self.sign()
+        } else {
+            other_sign
+        };
+        Self::saturate_sign(sign)
+    }
+
+    /// Returns the bound that `self * other / divisor` overflowed.
+    fn saturate_product(self, other: &Self, divisor: &Self) -> Self {
+        let sign = self.sign().flip_if(other.sign() != divisor.sign());
+        Self::saturate_sign(sign)
+    }
+
+    /// Wraps the value with the given sign and absolute value around the
+    /// boundaries of `T` using two's complement arithmetic, as the EVM does.
+    /// Values of unbounded types never wrap.
+    fn wrap(sign: FixedPointSign, abs: Wide<T>) -> Self {
+        let (Some(min), Some(max)) = (T::MIN, T::MAX) else {
+            return Self::from_sign_and_abs(sign, T::Unsigned::try_from_wide(abs).unwrap())
+                .unwrap();
+        };
+        let max = Wide::<T>::from(max.unsigned_abs());
+        let modulus = max.clone() + Wide::<T>::from(min.unsigned_abs()) + Wide::<T>::one();
+        let mut value = abs % modulus.clone();
+        if sign.is_negative() && !value.is_zero() {
+            value = modulus.clone() - value;
+        }
+        let (sign, abs) = if value > max {
+            (FixedPointSign::Negative, modulus - value)
+        } else {
+            (FixedPointSign::Positive, value)
+        };
+        // The absolute value is within the bounds of `T` at this point.
+        Self::from_sign_and_abs(sign, T::Unsigned::try_from_wide(abs).unwrap()).unwrap()
+    }
+
+    /// Computes the sign, quotient, and remainder of `self * other / divisor`,
+    /// wrapping the product around the boundaries of `T` before dividing.
+    fn wrapping_mul_div_abs(
+        &self,
+        other: &Self,
+        divisor: &Self,
+    ) -> (FixedPointSign, Wide<T>, Wide<T>) {
+        if divisor.is_zero() {
+            panic!("Cannot divide by zero: {self} * {other} / {divisor}");
+        }
+
+        let sign = self.sign().flip_if(other.is_negative());
+        let product = Self::full_mul(self.raw().unsigned_abs(), other.raw().unsigned_abs());
+        let product = Self::wrap(sign, product);
+        let denominator = Wide::<T>::from(divisor.raw().unsigned_abs());
+
+        let sign = product.sign().flip_if(divisor.is_negative());
+        let (abs, rem) = Wide::<T>::from(product.raw().unsigned_abs()).div_mod(denominator);
+        (sign, abs, rem)
+    }
+
+    /// Computes the absolute value of `self * other / divisor` using a wide
+    /// intermediate, rounded with the given mode.
+    fn mul_div_abs(&self, other: &Self, divisor: &Self, rounding: RoundingMode) -> Result<Wide<T>> {
+        if divisor.is_zero() {
+            return Err(FixedPointError::division_by_zero(
+                Operation::MulDiv,
+                &[self, other, divisor],
+            ));
+        }
+
+        let numerator = Self::full_mul(self.raw().unsigned_abs(), other.raw().unsigned_abs());
+        let denominator = Wide::<T>::from(divisor.raw().unsigned_abs());
+        let (quotient, remainder) = numerator.div_mod(denominator.clone());
+        let sign = self.sign().flip_if(other.sign() != divisor.sign());
+        Ok(rounding.round(sign, quotient, remainder, denominator))
+    }
+
+    /// Multiplies two absolute values without overflow.
+    fn full_mul(lhs: T::Unsigned, rhs: T::Unsigned) -> Wide<T> {
+        Wide::<T>::from(lhs) * Wide::<T>::from(rhs)
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> Neg for FixedPoint<T, D> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(self.raw().flip_sign())
+    }
+}
+
+/// Takes a mapping of operator traits to `FixedPoint` methods and implements
+/// the operator and assignment operator for each one.
+macro_rules! mapped_operator_impls {
+    ($($trait:ident => $fn:ident),*) => {
+        $(
+            paste::paste! {
+
+                impl<T: FixedPointValue, const D: u8> core::ops::$trait for FixedPoint<T, D> {
+                    type Output = Self;
+
+                    fn [<$trait:lower>](self, other: Self) -> Self::Output {
+                        self.$fn(other)
+                    }
+                }
+
+                impl<T: FixedPointValue, const D: u8> core::ops::[<$trait Assign>] for FixedPoint<T, D> {
+                    fn [<$trait:lower _assign>](&mut self, other: Self) {
+                        *self = core::ops::$trait::[<$trait:lower>](self.clone(), other);
+                    }
+                }
+            }
+        )*
+    };
+    ($($tt:tt)*) => {};
+}
+
+mapped_operator_impls!(
+    // use `mul_down` for `*` and `*=`.
+    Mul => mul_down,
+    // use `div_down` for `/` and `/=`.
+    Div => div_down
+);
+
+/// Takes a mapping of operator traits to checked `FixedPoint` methods and
+/// implements the operator and assignment operator for each one, panicking if
+/// the checked method returns an error.
+macro_rules! checked_operator_impls {
+    ($($trait:ident => $fn:ident),*) => {
+        $(
+            paste::paste! {
+
+                impl<T: FixedPointValue, const D: u8> core::ops::$trait for FixedPoint<T, D> {
+                    type Output = Self;
+
+                    fn [<$trait:lower>](self, other: Self) -> Self::Output {
+                        self.$fn(other).unwrap()
+                    }
+                }
+
+                impl<T: FixedPointValue, const D: u8> core::ops::[<$trait Assign>] for FixedPoint<T, D> {
+                    fn [<$trait:lower _assign>](&mut self, other: Self) {
+                        *self = core::ops::$trait::[<$trait:lower>](self.clone(), other);
+                    }
+                }
+            }
+        )*
+    };
+    ($($tt:tt)*) => {};
+}
+
+checked_operator_impls!(
+    // use `checked_add` for `+` and `+=`.
+    Add => checked_add,
+    // use `checked_sub` for `-` and `-=`.
+    Sub => checked_sub,
+    // use `checked_rem` for `%` and `%=`.
+    Rem => checked_rem
+);
+
+#[cfg(test)]
+mod tests {
+    use std::panic;
+
+    use ethers::{
+        signers::Signer,
+        types::{I256, U256},
+    };
+    use eyre::Result;
+    use hyperdrive_wrappers::wrappers::mock_fixed_point_math::MockFixedPointMath;
+    use primitive_types::U512;
+    use rand::{thread_rng, Rng};
+    use test_utils::{chain::Chain, constants::DEPLOYER};
+
+    use super::*;
+    use crate::{fixed, fixed_i128, fixed_i256, fixed_u128, fixed_u256, uint256, wide::U1024};
+
+    /// The maximum number that can be divided by another in the Solidity
+    /// implementation.
+    fn max_sol_numerator() -> FixedPoint<U256> {
+        (U256::MAX / uint256!(1e18)).into()
+    }
+
+    /// The number of decimals the reference functions compute with.
+    const REFERENCE_DECIMALS: usize = 50;
+
+    /// Converts a value to its absolute value scaled by `10^50`.
+    fn to_reference(x: FixedPoint<I256>) -> U1024 {
+        U1024::from(U512::from(x.raw().unsigned_abs())) * U1024::exp10(REFERENCE_DECIMALS - 18)
+    }
+
+    /// Rounds the result of a reference function to 36 decimals.
+    fn from_reference(sign: FixedPointSign, abs: U1024) -> Result<FixedPoint<I256, 36>> {
+        let divisor = U1024::exp10(REFERENCE_DECIMALS - 36);
+        let abs = (abs + divisor / 2) / divisor;
+        Ok(FixedPoint::from_sign_and_abs(
+            sign,
+            U256::from_dec_str(&abs.to_string())?,
+        )?)
+    }
+
+    /// Computes `e^x` for `x` scaled by `10^50`, by summing the Taylor series
+    /// of `e^(x / 2^k)` and squaring it `k` times. This shares nothing with
+    /// the range reduction and series of [`Accuracy::Precise`], so the tests
+    /// can use it as an independent reference.
+    fn reference_exp(sign: FixedPointSign, x: U1024) -> U1024 {
+        let one = U1024::exp10(REFERENCE_DECIMALS);
+        let mut k = 0;
+        while x >> k > one >> 10 {
+            k += 1;
+        }
+        let r = x >> k;
+        let (mut sum, mut term) = (one, one);
+        for n in 1_u64.. {
+            term = term * r / (one * n);
+            if term.is_zero() {
+                break;
+            }
+            sum += term;
+        }
+        for _ in 0..k {
+            sum = sum * sum / one;
+        }
+        match sign {
+            FixedPointSign::Positive => sum,
+            FixedPointSign::Negative => one * one / sum,
+        }
+    }
+
+    /// Computes `ln(x)` for a positive `x` scaled by `10^50` with Halley's
+    /// method on [`reference_exp`], starting from the `f64` logarithm.
+    fn reference_ln(x: U1024) -> (FixedPointSign, U1024) {
+        let one = U1024::exp10(REFERENCE_DECIMALS);
+        if x < one {
+            let (_, ln) = reference_ln(one * one / x);
+            return (FixedPointSign::Negative, ln);
+        }
+        let guess = (x.to_string().parse::<f64>().unwrap() / 1e50).ln().max(0.0);
+        let mut y = U1024::from((guess * 1e15) as u128) * U1024::exp10(REFERENCE_DECIMALS - 15);
+        for _ in 0..4 {
+            // `y += 2 * (x - e^y) / (x + e^y)`
+            let e = reference_exp(FixedPointSign::Positive, y);
+            let (difference, sign) = match x >= e {
+                true => (x - e, FixedPointSign::Positive),
+                false => (e - x, FixedPointSign::Negative),
+            };
+            let step = difference * 2 * one / (x + e);
+            y = match sign {
+                FixedPointSign::Positive => y + step,
+                FixedPointSign::Negative => y.saturating_sub(step),
+            };
+        }
+        (FixedPointSign::Positive, y)
+    }
+
+    /// Computes `x^y` for a positive `x` as `e^(y * ln(x))` with the
+    /// reference functions.
+    fn reference_pow(x: FixedPoint<I256>, y: FixedPoint<I256>) -> Result<FixedPoint<I256, 36>> {
+        let (sign, ln) = reference_ln(to_reference(x));
+        let z = ln * to_reference(y) / U1024::exp10(REFERENCE_DECIMALS);
+        from_reference(
+            FixedPointSign::Positive,
+            reference_exp(sign.flip_if(y.is_negative()), z),
+        )
+    }
+
+    #[test]
+    fn test_sub_failure() {
+        // Ensure that subtraction underflow panics.
+        assert!(panic::catch_unwind(|| fixed_u256!(1e18) - fixed!(2e18)).is_err());
+    }
+
+    #[test]
+    fn test_checked_add_sub() -> Result<()> {
+        assert_eq!(fixed_u256!(1e18).checked_add(fixed!(2e18))?, fixed!(3e18));
+        assert_eq!(fixed_u256!(3e18).checked_sub(fixed!(2e18))?, fixed!(1e18));
+        assert_eq!(fixed_i128!(1e18).checked_sub(fixed!(3e18))?, fixed!(-2e18));
+        assert_eq!(fixed_i128!(-1e18).checked_add(fixed!(3e18))?, fixed!(2e18));
+        assert_eq!(
+            FixedPoint::<i128>::MIN.checked_add(fixed!(1))?,
+            FixedPoint::new(i128::MIN + 1)
+        );
+
+        // Overflow and underflow are returned as errors with the operands.
+        let err = fixed_u256!(1e18).checked_sub(fixed!(2e18)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "FixedPoint operation overflowed: 1.000000000000000000 - 2.000000000000000000"
+        );
+        assert!(FixedPoint::<U256>::MAX.checked_add(fixed!(1)).is_err());
+        assert!(FixedPoint::<i128>::MAX.checked_add(fixed!(1)).is_err());
+        assert!(FixedPoint::<i128>::MIN.checked_sub(fixed!(1)).is_err());
+        assert!(FixedPoint::<i128>::MIN
+            .checked_sub(FixedPoint::<i128>::MAX)
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_mul_div() -> Result<()> {
+        assert_eq!(
+            fixed_u256!(1.5e18).checked_mul_down(fixed!(2e18))?,
+            fixed!(3e18)
+        );
+        assert_eq!(fixed_u256!(1).checked_mul_down(fixed!(0.5e18))?, fixed!(0));
+        assert_eq!(fixed_u256!(1).checked_mul_up(fixed!(0.5e18))?, fixed!(1));
+        assert_eq!(fixed_i128!(-1).checked_div_down(fixed!(3e18))?, fixed!(0));
+        assert_eq!(fixed_i128!(-1).checked_div_up(fixed!(3e18))?, fixed!(-1));
+        assert_eq!(
+            fixed_u256!(1e18).checked_mul_div_down(fixed!(2e18), fixed!(3e18))?,
+            fixed!(666666666666666666)
+        );
+        assert_eq!(
+            fixed_u256!(1e18).checked_mul_div_up(fixed!(2e18), fixed!(3e18))?,
+            fixed!(666666666666666667)
+        );
+
+        // Division by zero and overflow are returned as errors.
+        let err = fixed_u256!(1e18).checked_div_down(fixed!(0)).unwrap_err();
+        assert_eq!(
+            err,
+            FixedPointError::DivisionByZero {
+                operation: Operation::MulDiv,
+                operands: vec![
+                    "1.000000000000000000".to_string(),
+                    "1.000000000000000000".to_string(),
+                    "0.000000000000000000".to_string(),
+                ],
+            }
+        );
+        assert!(FixedPoint::<U256>::MAX
+            .checked_mul_down(fixed!(2e18))
+            .is_err());
+        assert!(FixedPoint::<U256>::MAX
+            .checked_mul_up(fixed!(1.5e18))
+            .is_err());
+        assert!(FixedPoint::<i128>::MAX
+            .checked_div_down(fixed!(0.5e18))
+            .is_err());
+        assert!(fixed_u128!(1e18)
+            .checked_mul_div_up(fixed!(1e18), fixed!(0))
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_pow() -> Result<()> {
+        assert_eq!(fixed_u256!(2e18).checked_pow(fixed!(0))?, fixed!(1e18));
+        assert_eq!(
+            fixed_u256!(2e18).checked_pow(fixed!(0.5e18))?,
+            fixed_u256!(2e18).pow(fixed!(0.5e18))?
+        );
+
+        // An exponent whose absolute value overflows is an error, not a panic.
+        assert!(fixed_i128!(2e18).checked_pow(FixedPoint::MIN).is_err());
+
+        // A result that doesn't fit in the type is an error.
+        assert!(matches!(
+            fixed_i128!(1e28).checked_pow(fixed!(3e18)),
+            Err(FixedPointError::Overflow {
+                operation: Operation::Pow,
+                ..
+            })
+        ));
+        assert!(matches!(
+            fixed_i128!(-1e18).checked_pow(fixed!(0.5e18)),
+            Err(FixedPointError::LnNonPositive { .. })
+        ));
+
+        // Types with fewer than 18 decimals are scaled to 18 decimals in the
+        // intermediate math, and the result is truncated to their decimals.
+        let x = FixedPoint::<u32, 9>::new(2_000_000_000_u32);
+        assert_eq!(
+            x.pow(FixedPoint::new(500_000_000_u32))?,
+            FixedPoint::new(1_414_213_562_u32)
+        );
+        assert_eq!(
+            x.pow(FixedPoint::new(1_500_000_000_u32))?,
+            FixedPoint::new(2_828_427_124_u32)
+        );
+        assert_eq!(
+            x.pow(FixedPoint::new(0_u32))?,
+            FixedPoint::new(1_000_000_000_u32)
+        );
+        assert_eq!(
+            FixedPoint::<u32, 9>::new(1_000_000_000_u32).exp_with(Accuracy::SolidityCompatible)?,
+            FixedPoint::new(2_718_281_828_u32)
+        );
+        assert_eq!(
+            x.ln_with(Accuracy::SolidityCompatible)?,
+            FixedPoint::new(693_147_180_u32)
+        );
+        assert!(x.pow(FixedPoint::new(3_000_000_000_u32)).is_err());
+        let x = FixedPoint::<i64, 9>::new(2_000_000_000);
+        assert_eq!(
+            x.pow(FixedPoint::new(-1_000_000_000))?,
+            FixedPoint::new(500_000_000)
+        );
+        assert_eq!(
+            x.pow_down(FixedPoint::new(-1_000_000_000))?,
+            FixedPoint::new(499_999_999)
+        );
+        assert_eq!(
+            x.pow_up(FixedPoint::new(-1_000_000_000))?,
+            FixedPoint::new(500_000_001)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_add_sub_with_decimals() -> Result<()> {
+        let usdc = FixedPoint::<U256, 6>::new(1_500_000_u128);
+        let sum = usdc + FixedPoint::new(250_000_u128);
+        assert_eq!(sum.raw(), U256::from(1_750_000));
+        assert_eq!(sum - usdc, FixedPoint::new(250_000_u128));
+
+        // Other scales are rescaled explicitly.
+        let sum = usdc.rescale(RoundingMode::HalfEven)? + fixed_u256!(0.25e18);
+        assert_eq!(sum, fixed_u256!(1.75e18));
+        Ok(())
+    }
+
+    #[test]
+    fn test_mul_div_with_decimals() -> Result<()> {
+        let usdc = FixedPoint::<U256, 6>::new(1_500_000_u128);
+        let wbtc = FixedPoint::<U256, 8>::new(200_000_000_u128);
+        let rate = FixedPoint::<U256, 6>::new(1_050_000_u128);
+
+        // Results are rounded to the type's decimals.
+        assert_eq!(usdc.mul_down(rate).raw(), U256::from(1_575_000));
+        assert_eq!(usdc.div_down(rate).raw(), U256::from(1_428_571));
+        assert_eq!(usdc.div_up(rate).raw(), U256::from(1_428_572));
+        assert_eq!(usdc.mul_div_down(usdc, rate).raw(), U256::from(2_142_857));
+        assert_eq!(usdc.mul_div_up(usdc, rate).raw(), U256::from(2_142_858));
+
+        // The same values with 8 decimals are rounded to more places.
+        let usdc_8: FixedPoint<U256, 8> = usdc.rescale(RoundingMode::HalfEven)?;
+        assert_eq!(wbtc.div_down(usdc_8), FixedPoint::new(133_333_333_u128));
+        assert_eq!(wbtc.div_up(usdc_8), FixedPoint::new(133_333_334_u128));
+
+        // Signed values.
+        let a = FixedPoint::<i128, 6>::new(-1_500_000);
+        let b = FixedPoint::<i128, 6>::new(3_000_000);
+        assert_eq!(a.mul_down(b).raw(), -4_500_000);
+        assert_eq!(a.div_down(b).raw(), -500_000);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pow_with_decimals() -> Result<()> {
+        let x = FixedPoint::<U256, 6>::new(2_000_000_u128);
+        let y = FixedPoint::<U256, 6>::new(500_000_u128);
+        let expected: FixedPoint<U256, 6> = fixed_u256!(2e18)
+            .pow(fixed!(0.5e18))?
+            .rescale(RoundingMode::TowardZero)?;
+        assert_eq!(x.pow(y)?, expected);
+        assert_eq!(x.pow_ratio(1, 2)?.raw(), U256::from(1_414_214));
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_base_pow() -> Result<()> {
+        // Integer exponents and fractions with odd denominators are real.
+        let x = fixed_i256!(-2e18);
+        assert_eq!(x.pow_with(fixed!(2e18), Accuracy::Precise)?, fixed!(4e18));
+        assert_eq!(x.pow_with(fixed!(3e18), Accuracy::Precise)?, fixed!(-8e18));
+        assert_eq!(
+            x.pow_with(fixed!(-3e18), Accuracy::Precise)?,
+            fixed!(-0.125e18)
+        );
+        assert_eq!(
+            fixed_i256!(-32e18).pow_with(fixed!(0.2e18), Accuracy::Precise)?,
+            fixed!(-2e18)
+        );
+        assert_eq!(
+            fixed_i256!(-32e18).pow_with(fixed!(0.4e18), Accuracy::Precise)?,
+            fixed!(4e18)
+        );
+        assert_eq!(x.pow_with(fixed!(0), Accuracy::Precise)?, fixed!(1e18));
+
+        // As in FixedPointMath.sol, `pow` and its bounds reject negative bases.
+        for pow in [
+            FixedPoint::pow,
+            FixedPoint::pow_down,
+            FixedPoint::pow_up,
+            |x, y| FixedPoint::pow_with(x, y, Accuracy::SolidityCompatible),
+        ] {
+            assert!(matches!(
+                pow(x, fixed!(3e18)),
+                Err(FixedPointError::LnNonPositive { .. })
+            ));
+        }
+
+        // Rational exponents are reduced to lowest terms.
+        assert_eq!(fixed_i256!(-27e18).pow_ratio(1, 3)?, fixed!(-3e18));
+        assert_eq!(fixed_i256!(-27e18).pow_ratio(2, 6)?, fixed!(-3e18));
+        assert_eq!(
+            fixed_i256!(-27e18).pow_ratio(-4, 6)?,
+            fixed!(0.111111111111111111e18)
+        );
+        assert_eq!(fixed_i128!(-8e18).pow_ratio(5, 3)?, fixed!(-32e18));
+        assert_eq!(
+            fixed_u256!(2e18).pow_ratio(1, 2)?,
+            fixed!(1.414213562373095049e18)
+        );
+
+        // Other exponents aren't real.
+        assert_eq!(
+            x.pow_with(fixed!(0.5e18), Accuracy::Precise),
+            Err(FixedPointError::NonRealPower {
+                base: "-2.000000000000000000".into(),
+                exponent: "0.500000000000000000".into()
+            })
+        );
+        assert!(matches!(
+            x.pow_with(fixed!(1.1e18), Accuracy::Precise),
+            Err(FixedPointError::NonRealPower { .. })
+        ));
+        assert_eq!(
+            x.pow_ratio(3, 4),
+            Err(FixedPointError::NonRealPower {
+                base: "-2.000000000000000000".into(),
+                exponent: "3/4".into()
+            })
+        );
+        assert!(matches!(
+            x.pow_ratio(1, 0),
+            Err(FixedPointError::DivisionByZero { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pow_bounds() -> Result<()> {
+        // The bounds surround the result of `pow` and the true value.
+        let x = fixed_i256!(2e18);
+        assert_eq!(x.pow(fixed!(-3e18))?, fixed!(0.125e18));
+        assert_eq!(x.pow_down(fixed!(-3e18))?, fixed!(0.124999999999999998e18));
+        assert_eq!(x.pow_up(fixed!(-3e18))?, fixed!(0.125000000000000003e18));
+
+        // Exact cases are exact.
+        assert_eq!(x.pow_down(fixed!(0))?, fixed!(1e18));
+        assert_eq!(x.pow_up(fixed!(0))?, fixed!(1e18));
+        assert_eq!(fixed_i256!(0).pow_down(fixed!(2e18))?, fixed!(0));
+        assert_eq!(fixed_i256!(0).pow_up(fixed!(2e18))?, fixed!(0));
+        assert!(matches!(
+            fixed_i256!(0).pow_up(fixed!(-2e18)),
+            Err(FixedPointError::DivisionByZero { .. })
+        ));
+
+        // The value's decimals are rounded in the direction of the bound.
+        let x = FixedPoint::<U256, 6>::new(1_500_000_u128);
+        let y = FixedPoint::<U256, 6>::new(2_500_000_u128);
+        assert_eq!(x.pow(y)?, FixedPoint::new(2_755_675_u128));
+        assert_eq!(x.pow_down(y)?, FixedPoint::new(2_755_675_u128));
+        assert_eq!(x.pow_up(y)?, FixedPoint::new(2_755_676_u128));
+        let x = "1.000000000000000000000001".parse::<FixedPoint<U256, 24>>()?;
+        assert!(x.pow_down(fixed!(1e24))? < fixed!(1e24));
+        assert!(x.pow_up(fixed!(1e24))? > fixed!(1.000000000000000001e24));
+
+        // Once the error bound exceeds 100%, only the lower bound is defined.
+        let x = fixed_u256!(1e18);
+        let y = fixed_u256!(1e40);
+        assert_eq!(x.pow(y)?, fixed!(1e18));
+        assert_eq!(x.pow_down(y)?, fixed!(0));
+        assert!(matches!(x.pow_up(y), Err(FixedPointError::Overflow { .. })));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzz_pow_bounds() -> Result<()> {
+        // Compare the bounds to an independent reference rounded to 36
+        // decimals, which is within `1e-36` of the true value.
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(0.001e18)..fixed!(1_000e18));
+            let y: FixedPoint<I256> = rng.gen_range(fixed!(-10e18)..fixed!(10e18));
+            let reference = reference_pow(x, y)?;
+            let down = x.pow_down(y)?.rescale::<36>(RoundingMode::TowardZero)?;
+            let up = x.pow_up(y)?.rescale::<36>(RoundingMode::TowardZero)?;
+            let ulp = FixedPoint::new(1);
+            assert!(down <= reference + ulp, "pow_down({x}, {y})");
+            assert!(up + ulp >= reference, "pow_up({x}, {y})");
+
+            // For positive exponents, the bounds are within a relative `1e-16`
+            // of the true value plus a few units in their last place. Negative
+            // exponents lose precision when `x^|y|` is small, as in `pow`.
+            if y.is_positive() {
+                let tolerance = reference.abs().mul_up(fixed!(0.0000000000000001e36))
+                    + fixed!(0.00000000000000001e36);
+                assert!(reference - down <= tolerance, "pow_down({x}, {y})");
+                assert!(up - reference <= tolerance, "pow_up({x}, {y})");
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mul_div_rounding_modes() -> Result<()> {
+        // -1 * 5 / 3 = -1.666...
+        let (a, b, c) = (fixed_i128!(-1), fixed!(5), fixed!(3));
+        assert_eq!(a.mul_div(b, c, RoundingMode::Floor), fixed!(-2));
+        assert_eq!(a.mul_div(b, c, RoundingMode::Ceil), fixed!(-1));
+        assert_eq!(a.mul_div(b, c, RoundingMode::TowardZero), fixed!(-1));
+        assert_eq!(a.mul_div(b, c, RoundingMode::AwayFromZero), fixed!(-2));
+        assert_eq!(a.mul_div(b, c, RoundingMode::HalfUp), fixed!(-2));
+        assert_eq!(a.mul_div(b, c, RoundingMode::HalfEven), fixed!(-2));
+
+        // The `_down` and `_up` methods are aliases of the directed modes.
+        assert_eq!(
+            a.mul_div_down(b, c),
+            a.mul_div(b, c, RoundingMode::TowardZero)
+        );
+        assert_eq!(
+            a.mul_div_up(b, c),
+            a.mul_div(b, c, RoundingMode::AwayFromZero)
+        );
+
+        // Ties.
+        let x = fixed_i128!(2.5e18);
+        assert_eq!(x.mul_with(fixed!(1), RoundingMode::HalfUp), fixed!(3));
+        assert_eq!(x.mul_with(fixed!(1), RoundingMode::HalfEven), fixed!(2));
+        assert_eq!((-x).mul_with(fixed!(1), RoundingMode::HalfUp), fixed!(-3));
+        assert_eq!((-x).mul_with(fixed!(1), RoundingMode::HalfEven), fixed!(-2));
+        assert_eq!(x.mul_with(fixed!(1), RoundingMode::HalfDown), fixed!(2));
+        assert_eq!((-x).mul_with(fixed!(1), RoundingMode::HalfDown), fixed!(-2));
+        assert_eq!(x.mul_with(fixed!(1), RoundingMode::HalfCeil), fixed!(3));
+        assert_eq!((-x).mul_with(fixed!(1), RoundingMode::HalfCeil), fixed!(-2));
+        assert_eq!(x.mul_with(fixed!(1), RoundingMode::HalfFloor), fixed!(2));
+        assert_eq!(
+            (-x).mul_with(fixed!(1), RoundingMode::HalfFloor),
+            fixed!(-3)
+        );
+        assert_eq!(
+            fixed_i128!(-1).div_with(fixed!(3e18), RoundingMode::HalfFloor),
+            fixed!(0)
+        );
+        assert_eq!(
+            fixed_i128!(7).div_with(fixed!(2e18), RoundingMode::HalfEven),
+            fixed!(4)
+        );
+        assert_eq!(
+            fixed_i128!(-1).div_with(fixed!(3e18), RoundingMode::Floor),
+            fixed!(-1)
+        );
+        assert_eq!(
+            fixed_i128!(-1).div_with(fixed!(3e18), RoundingMode::Ceil),
+            fixed!(0)
+        );
+
+        // Errors are still returned by the checked versions.
+        assert!(fixed_i128!(1)
+            .checked_div(fixed!(0), RoundingMode::HalfEven)
+            .is_err());
+        assert!(FixedPoint::<i128>::MAX
+            .checked_mul(fixed!(2e18), RoundingMode::Floor)
+            .is_err());
+
+        // The operator traits' methods aren't shadowed.
+        {
+            use core::ops::{Div, Mul};
+            assert_eq!(x.mul(fixed!(2e18)), fixed!(5e18));
+            assert_eq!(x.div(fixed!(2e18)), fixed!(1.25e18));
+        }
+
+        // The assignment operators still use the `_down` methods.
+        let mut y = fixed_i128!(-1);
+        y *= fixed!(0.5e18);
+        assert_eq!(y, fixed!(0));
+        y = fixed!(-1e18);
+        y /= fixed!(3e18);
+        assert_eq!(y, fixed!(-333333333333333333));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_saturating() {
+        // Results that don't overflow are unaffected.
+        assert_eq!(fixed_u256!(1e18).saturating_add(fixed!(2e18)), fixed!(3e18));
+        assert_eq!(
+            fixed_i128!(1e18).saturating_sub(fixed!(3e18)),
+            fixed!(-2e18)
+        );
+
+        // Results that overflow are clamped to the bounds.
+        assert_eq!(
+            FixedPoint::<U256>::MAX.saturating_add(fixed!(1)),
+            FixedPoint::MAX
+        );
+        assert_eq!(fixed_u256!(1e18).saturating_sub(fixed!(2e18)), fixed!(0));
+        assert_eq!(
+            FixedPoint::<i128>::MIN.saturating_sub(fixed!(1)),
+            FixedPoint::MIN
+        );
+        assert_eq!(
+            FixedPoint::<i128>::MAX.saturating_sub(fixed!(-1)),
+            FixedPoint::MAX
+        );
+        assert_eq!(
+            FixedPoint::<U256>::MAX.saturating_mul_down(fixed!(2e18)),
+            FixedPoint::MAX
+        );
+        assert_eq!(
+            FixedPoint::<I256>::MAX.saturating_mul_up(fixed!(-2e18)),
+            FixedPoint::MIN
+        );
+        assert_eq!(
+            FixedPoint::<i128>::MIN.saturating_div_down(fixed!(-0.5e18)),
+            FixedPoint::MAX
+        );
+        assert_eq!(
+            FixedPoint::<I256>::MIN.saturating_mul_div_down(fixed!(1), fixed!(-1)),
+            FixedPoint::MAX
+        );
+
+        // Division by zero still panics.
+        assert!(panic::catch_unwind(|| fixed_u256!(1e18).saturating_div_down(fixed!(0))).is_err());
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(FixedPoint::<U256>::MAX.wrapping_add(fixed!(1)), fixed!(0));
+        assert_eq!(fixed_u256!(0).wrapping_sub(fixed!(1)), FixedPoint::MAX);
+        assert_eq!(
+            FixedPoint::<I256>::MAX.wrapping_add(fixed!(1)),
+            FixedPoint::MIN
+        );
+        assert_eq!(
+            FixedPoint::<i128>::MIN.wrapping_sub(fixed!(1)),
+            FixedPoint::MAX
+        );
+
+        // `type(int256).min * 1 / -1` wraps back to the minimum in the EVM.
+        assert_eq!(
+            FixedPoint::<I256>::MIN.wrapping_mul_div_down(fixed!(1), fixed!(-1)),
+            FixedPoint::MIN
+        );
+
+        // The product wraps before it's divided.
+        assert_eq!(
+            FixedPoint::<U256>::MAX.wrapping_mul_down(fixed!(2e18)),
+            FixedPoint::from(U256::MAX.overflowing_mul(uint256!(2e18)).0 / uint256!(1e18))
+        );
+        assert_eq!(
+            FixedPoint::<U256>::MAX.wrapping_mul_up(fixed!(2e18)),
+            FixedPoint::from(U256::MAX.overflowing_mul(uint256!(2e18)).0 / uint256!(1e18) + 1)
+        );
+
+        // Division by zero panics.
+        assert!(panic::catch_unwind(|| fixed_u256!(1e18).wrapping_div_down(fixed!(0))).is_err());
+    }
+
+    #[test]
+    fn test_integer_division() -> Result<()> {
+        // Truncated division matches `/` and `%` on the raw values.
+        let (quotient, remainder) = fixed_i256!(-2.5e18).div_rem(fixed!(0.75e18));
+        assert_eq!(quotient, I256::from(-3));
+        assert_eq!(remainder, fixed!(-0.25e18));
+        assert_eq!(fixed_i256!(-2.5e18) % fixed!(0.75e18), remainder);
+        let (quotient, remainder) = fixed_u256!(10e18).div_rem(fixed!(3e18));
+        assert_eq!(quotient, uint256!(3));
+        assert_eq!(remainder, fixed!(1e18));
+
+        // Euclidean division keeps the remainder non-negative.
+        assert_eq!(
+            fixed_i256!(-2.5e18).div_euclid(fixed!(0.75e18)),
+            fixed!(-4e18)
+        );
+        assert_eq!(
+            fixed_i256!(-2.5e18).rem_euclid(fixed!(0.75e18)),
+            fixed!(0.5e18)
+        );
+        assert_eq!(
+            fixed_i256!(-2.5e18).div_euclid(fixed!(-0.75e18)),
+            fixed!(4e18)
+        );
+        assert_eq!(
+            fixed_i256!(-2.5e18).rem_euclid(fixed!(-0.75e18)),
+            fixed!(0.5e18)
+        );
+        assert_eq!(
+            fixed_i256!(2.5e18).div_euclid(fixed!(-0.75e18)),
+            fixed!(-3e18)
+        );
+        assert_eq!(fixed_i256!(-3e18).rem_euclid(fixed!(1.5e18)), fixed!(0));
+
+        // Values with fewer decimals work the same.
+        let x = FixedPoint::<i128, 6>::from(7_500_000);
+        let (quotient, remainder) = x.div_rem(FixedPoint::from(2_000_000));
+        assert_eq!((quotient, remainder.decimals()), (3, 6));
+        assert_eq!(remainder, FixedPoint::from(1_500_000));
+
+        // `MIN` edges.
+        let min = FixedPoint::<i128>::MIN;
+        assert_eq!(min % fixed!(-1), fixed!(0));
+        assert_eq!(min.rem_euclid(fixed!(-1)), fixed!(0));
+        assert_eq!(min.div_rem(min), (1, fixed!(0)));
+        assert_eq!(
+            min.div_rem(fixed!(-1e18)),
+            (
+                i128::MIN / -10_i128.pow(18),
+                fixed!(-0.687303715884105728e18)
+            )
+        );
+        assert!(min.checked_div_rem(fixed!(-1)).is_err());
+        assert!(min.checked_div_euclid(fixed!(-1e18)).is_err());
+        // The Euclidean quotient of `MIN` rounds away from zero, so it can
+        // overflow even when the truncated one fits.
+        assert!(min.checked_div_euclid(fixed!(1e18)).is_err());
+        assert_eq!(
+            min.div_euclid(fixed!(2e18)).raw(),
+            i128::MIN.div_euclid(2 * 10_i128.pow(18)) * 10_i128.pow(18)
+        );
+        assert_eq!(min.rem_euclid(FixedPoint::MAX), FixedPoint::MAX - fixed!(1));
 
-use ethers::types::U256;
-use eyre::{bail, eyre, Result};
+        // Integer division scales the raw value without the decimals.
+        assert_eq!(
+            fixed_u256!(10e18).div_int(3_u128, RoundingMode::Floor),
+            fixed!(3.333333333333333333e18)
+        );
+        assert_eq!(
+            fixed_u256!(10e18).div_int(3_u128, RoundingMode::Ceil),
+            fixed!(3.333333333333333334e18)
+        );
+        assert_eq!(
+            fixed_i128!(-10e18).div_int(-4, RoundingMode::Floor),
+            fixed!(2.5e18)
+        );
+        assert_eq!(
+            fixed_i128!(10e18).div_int(-3, RoundingMode::Floor),
+            fixed!(-3.333333333333333334e18)
+        );
+        assert_eq!(min.div_int(1, RoundingMode::Floor), min);
+        assert_eq!(
+            min.checked_div_int(-1, RoundingMode::Floor),
+            Err(FixedPointError::overflow(
+                Operation::Div,
+                &[min.to_string(), "-1".into()]
+            ))
+        );
+        assert_eq!(
+            fixed_i128!(1e18).checked_div_int(0, RoundingMode::Floor),
+            Err(FixedPointError::division_by_zero(
+                Operation::Div,
+                &["1.000000000000000000", "0"]
+            ))
+        );
 
-use crate::{exp, ln, FixedPoint, FixedPointValue};
+        // Division by zero is an error.
+        assert_eq!(
+            fixed_i256!(1e18).checked_rem(fixed!(0)),
+            Err(FixedPointError::division_by_zero(
+                Operation::Rem,
+                &["1.000000000000000000", "0.000000000000000000"]
+            ))
+        );
+        assert!(fixed_i256!(1e18).checked_div_euclid(fixed!(0)).is_err());
+        assert!(fixed_i256!(1e18).checked_div_rem(fixed!(0)).is_err());
 
-impl<T: FixedPointValue> FixedPoint<T> {
-    /// Computes the absolute value of self.
-    ///
-    /// # Panics
-    ///
-    /// If the absolute value of self overflows `T`, e.g., if self is the
-    /// minimum value of a signed integer.
-    pub fn abs(&self) -> Self {
-        self.raw().abs().into()
+        Ok(())
     }
 
-    /// Computes the absolute value of self as a `U256` to avoid overflow.
-    pub fn unsigned_abs(&self) -> FixedPoint<U256> {
-        self.raw().unsigned_abs().into()
-    }
+    #[test]
+    fn fuzz_integer_division() {
+        // Fuzz the integer division against the primitive operations on the
+        // raw values.
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let a: FixedPoint<i128> = rng.gen();
+            let b: FixedPoint<i128> =
+                FixedPoint::from(rng.gen_range(-10_i128.pow(24)..10_i128.pow(24)));
+            if b.is_zero() {
+                continue;
+            }
+            let (quotient, remainder) = a.div_rem(b);
+            assert_eq!(quotient, a.raw() / b.raw());
+            assert_eq!(remainder.raw(), a.raw() % b.raw());
+            assert_eq!((a % b).raw(), a.raw() % b.raw());
+            assert_eq!(a.rem_euclid(b).raw(), a.raw().rem_euclid(b.raw()));
+            match a.raw().div_euclid(b.raw()).checked_mul(10_i128.pow(18)) {
+                Some(expected) => assert_eq!(a.div_euclid(b).raw(), expected),
+                None => assert!(a.checked_div_euclid(b).is_err()),
+            }
 
-    pub fn abs_diff(&self, other: Self) -> FixedPoint<U256> {
-        let abs_self = self.unsigned_abs();
-        let abs_other = other.unsigned_abs();
-        if self.sign() != other.sign() {
-            abs_self + abs_other
-        } else if abs_self > abs_other {
-            abs_self - abs_other
-        } else {
-            abs_other - abs_self
+            let n = rng.gen_range(-1_000_000_i128..1_000_000);
+            if n != 0 {
+                assert_eq!(a.div_int(n, RoundingMode::TowardZero).raw(), a.raw() / n);
+                assert_eq!(
+                    a.div_int(n, RoundingMode::Floor).raw(),
+                    a.raw().div_euclid(n) - i128::from(n < 0 && a.raw().rem_euclid(n) != 0)
+                );
+            }
         }
     }
 
-    pub fn mul_div_down(self, other: Self, divisor: Self) -> Self {
-        if divisor.is_zero() {
-            panic!("Cannot divide by zero.");
-        }
-        let sign = self.sign().flip_if(other.sign() != divisor.sign());
-        let abs = U256::try_from(
-            self.raw()
-                .unsigned_abs()
-                .full_mul(other.raw().unsigned_abs())
-                .div(divisor.raw().unsigned_abs()),
-        )
-        .map_err(|_| eyre!("FixedPoint operation overflowed: {self} * {other} / {divisor}"))
-        .unwrap();
-        Self::from_sign_and_abs(sign, abs).unwrap()
-    }
+    #[test]
+    fn test_u512() -> Result<()> {
+        // Sums that overflow `U256` can be accumulated in a `U512`.
+        let max = FixedPoint::<U256>::MAX.change_type::<U512>()?;
+        let sum = max + max;
+        assert_eq!(sum.raw(), U512::from(U256::MAX) * 2);
+        assert!(sum.change_type::<U256>().is_err());
+        assert_eq!(sum.mul_div_down(fixed!(1.5e18), fixed!(3e18)), max);
+
+        let a: FixedPoint<U512> = fixed!(1e80);
+        assert_eq!(
+            a.to_string(),
+            format!("1{}.{}", "0".repeat(62), "0".repeat(18))
+        );
+        assert_eq!(a.mul_down(a), fixed!(1e142));
+        assert!(a.checked_mul_down(a.mul_down(a)).is_err());
+
+        // The bounds of `U512` are handled like any other value.
+        assert!(FixedPoint::<U512>::MAX.checked_add(fixed!(1)).is_err());
+        assert_eq!(FixedPoint::<U512>::MAX.wrapping_add(fixed!(1)), fixed!(0));
+        assert_eq!(
+            FixedPoint::<U512>::MAX.saturating_mul_down(fixed!(2e18)),
+            FixedPoint::MAX
+        );
 
-    pub fn mul_div_up(self, other: Self, divisor: Self) -> Self {
-        if divisor.is_zero() {
-            panic!("Cannot divide by zero.");
-        }
-        let sign = self.sign().flip_if(other.sign() != divisor.sign());
-        let (abs_u512, rem) = self
-            .raw()
-            .unsigned_abs()
-            .full_mul(other.raw().unsigned_abs())
-            .div_mod(divisor.raw().unsigned_abs().into());
-        let abs = U256::try_from(abs_u512)
-            .map_err(|_| eyre!("FixedPoint operation overflowed: {self} * {other} / {divisor}"))
-            .unwrap();
-        Self::from_sign_and_abs(sign, abs).unwrap()
-            + Self::from_sign_and_abs(sign, U256::from(!rem.is_zero() as u8)).unwrap()
+        Ok(())
     }
 
-    pub fn mul_down(self, other: Self) -> Self {
-        self.mul_div_down(other, self.one())
+    #[test]
+    fn test_small_ints() -> Result<()> {
+        // 64-bit values support up to 18 decimals.
+        let a: FixedPoint<u64> = fixed!(1.5e18);
+        assert_eq!(a.mul_down(fixed!(2e18)), fixed!(3e18));
+        assert_eq!(a.div_up(fixed!(7e18)), fixed!(0.214285714285714286e18));
+        assert!(a.checked_mul_down(fixed!(15e18)).is_err());
+        assert_eq!(a.to_u128()?, 1_500_000_000_000_000_000);
+
+        let b: FixedPoint<i64> = fixed!(-1.5e18);
+        assert_eq!(b.mul_up(fixed!(2.5e18)), fixed!(-3.75e18));
+        assert_eq!(b.saturating_mul_down(fixed!(7e18)), FixedPoint::MIN);
+        assert_eq!(b.unsigned_abs().to_u256()?, U256::from(a.raw()));
+        assert!(b.change_type::<u64>().is_err());
+
+        // 32-bit values need fewer decimals.
+        let c = FixedPoint::<u32, 6>::new(2_500_000_u32);
+        assert_eq!(c.to_string(), "2.500000");
+        assert_eq!(c.mul_down(c), FixedPoint::new(6_250_000_u32));
+        assert_eq!(u64::try_from(c)?, 2_500_000);
+        assert_eq!(FixedPoint::<i128>::from(c.raw()).raw(), 2_500_000);
+
+        // Products fit in the native wide types, and roots, logarithms, and
+        // float conversions use more digits.
+        let max = FixedPoint::<u64>::MAX;
+        assert_eq!(max.mul_div_down(max, max), max);
+        assert_eq!(
+            FixedPoint::<i64>::MIN.wrapping_sub(fixed!(1)),
+            FixedPoint::MAX
+        );
+        let two = FixedPoint::<u32, 9>::new(2_000_000_000_u32);
+        assert_eq!(
+            two.cbrt(RoundingMode::Floor)?,
+            FixedPoint::new(1_259_921_049_u32)
+        );
+        assert_eq!(two.ln()?, FixedPoint::new(693_147_181_u32));
+        assert_eq!(FixedPoint::<u32, 9>::MAX.to_f64(), 4.294967295);
+        assert_eq!(fixed_i128!(8e18).log2()?, fixed!(3e18));
+
+        Ok(())
     }
 
-    pub fn mul_up(self, other: Self) -> Self {
-        self.mul_div_up(other, self.one())
+    #[test]
+    fn fuzz_u512() -> Result<()> {
+        // Values in the `U256` range behave the same as a `U512`.
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let a: FixedPoint<U256> = rng.gen();
+            let b: FixedPoint<U256> = rng.gen();
+            let c: FixedPoint<U256> = rng.gen();
+            let wide = |x: FixedPoint<U256>| x.change_type::<U512>().unwrap();
+            if let Ok(expected) = a.checked_mul_div_down(b, c) {
+                assert_eq!(
+                    wide(a).checked_mul_div_down(wide(b), wide(c))?,
+                    wide(expected)
+                );
+            }
+            if let Ok(expected) = a.checked_mul_div_up(b, c) {
+                assert_eq!(
+                    wide(a).checked_mul_div_up(wide(b), wide(c))?,
+                    wide(expected)
+                );
+            }
+            assert_eq!(
+                wide(a).wrapping_add(wide(b)).raw(),
+                U512::from(a.raw()) + U512::from(b.raw())
+            );
+        }
+        Ok(())
     }
 
-    pub fn div_down(self, other: Self) -> Self {
-        self.mul_div_down(self.one(), other)
+    #[test]
+    fn test_rounding() -> Result<()> {
+        // Integer parts of signed values.
+        let x = fixed_i256!(-1.5e18);
+        assert_eq!(x.floor(), fixed!(-2e18));
+        assert_eq!(x.ceil(), fixed!(-1e18));
+        assert_eq!(x.trunc(), fixed!(-1e18));
+        assert_eq!(x.fract(), fixed!(-0.5e18));
+        assert_eq!(x.round(RoundingMode::HalfEven), fixed!(-2e18));
+        assert_eq!(x.round(RoundingMode::HalfUp), fixed!(-2e18));
+        assert_eq!(
+            fixed_i256!(-2.5e18).round(RoundingMode::HalfEven),
+            fixed!(-2e18)
+        );
+        assert_eq!(fixed_i256!(3e18).floor(), fixed!(3e18));
+        assert_eq!(fixed_i256!(3e18).fract(), fixed!(0));
+        assert_eq!(fixed_u256!(0.999e18).ceil(), fixed!(1e18));
+
+        // The bounds round towards zero without overflowing, but can't round
+        // away from it.
+        let min = FixedPoint::<I256>::MIN;
+        assert_eq!(
+            min.trunc().to_string(),
+            "-57896044618658097711785492504343953926634992332820282019728.000000000000000000"
+        );
+        assert_eq!(min.ceil(), min.trunc());
+        assert_eq!(min.fract(), fixed!(-0.792003956564819968e18));
+        assert!(matches!(
+            min.checked_round(RoundingMode::Floor),
+            Err(FixedPointError::Overflow {
+                operation: Operation::Quantize,
+                ..
+            })
+        ));
+        assert!(FixedPoint::<I256>::MAX
+            .checked_round(RoundingMode::Ceil)
+            .is_err());
+        assert_eq!(FixedPoint::<I256, 0>::MIN.floor(), FixedPoint::MIN);
+        assert_eq!(FixedPoint::<i128, 0>::MIN.fract(), FixedPoint::new(0));
+
+        // Rounding to a step, which may be negative.
+        let price = fixed_u256!(1.234e18);
+        assert_eq!(
+            price.quantize(fixed!(0.05e18), RoundingMode::HalfUp),
+            fixed!(1.25e18)
+        );
+        assert_eq!(
+            price.quantize(fixed!(0.05e18), RoundingMode::Floor),
+            fixed!(1.2e18)
+        );
+        assert_eq!(
+            fixed_i256!(-1.3e18).quantize(fixed!(-0.5e18), RoundingMode::HalfEven),
+            fixed!(-1.5e18)
+        );
+        assert_eq!(
+            fixed_i256!(-7e18).quantize(fixed!(3e18), RoundingMode::Ceil),
+            fixed!(-6e18)
+        );
+        assert!(matches!(
+            price.checked_quantize(fixed!(0), RoundingMode::Floor),
+            Err(FixedPointError::DivisionByZero { .. })
+        ));
+        assert!(FixedPoint::<U256>::MAX
+            .checked_quantize(fixed!(1e18), RoundingMode::Ceil)
+            .is_err());
+
+        // Integer conversions truncate.
+        assert_eq!(fixed_i256!(-2.9e18).to_integer::<i64>()?, -2);
+        assert_eq!(fixed_u256!(2.9e18).to_integer::<u8>()?, 2);
+        assert_eq!(fixed_u128!(255.5e18).to_integer::<u128>()?, 255);
+        assert_eq!(
+            min.to_integer::<I256>()?,
+            min.trunc().rescale::<0>(RoundingMode::TowardZero)?.raw()
+        );
+        assert!(matches!(
+            fixed_u256!(256e18).to_integer::<u8>(),
+            Err(FixedPointError::OutOfRange { target: "u8", .. })
+        ));
+        assert!(fixed_i256!(-1e18).to_integer::<u64>().is_err());
+
+        Ok(())
     }
 
-    pub fn div_up(self, other: Self) -> Self {
-        self.mul_div_up(self.one(), other)
+    #[test]
+    fn fuzz_rounding() {
+        let mut rng = thread_rng();
+        let one = fixed_i256!(1e18);
+        for _ in 0..10_000 {
+            let x: FixedPoint<I256> = rng.gen();
+            let trunc = x.trunc();
+            assert_eq!(trunc + x.fract(), x);
+            assert!(trunc.unsigned_abs() <= x.unsigned_abs());
+            if let (Ok(floor), Ok(ceil)) = (
+                x.checked_round(RoundingMode::Floor),
+                x.checked_round(RoundingMode::Ceil),
+            ) {
+                assert!(floor <= x && x < floor + one);
+                assert!(ceil >= x && x > ceil - one);
+                assert_eq!(floor.fract(), fixed!(0));
+                assert_eq!(ceil.fract(), fixed!(0));
+
+                let round = x.round(RoundingMode::HalfEven);
+                assert!(round == floor || round == ceil);
+                assert!((round - x).unsigned_abs() <= fixed!(0.5e18));
+            }
+
+            let step: FixedPoint<I256> = rng.gen_range(fixed!(1)..fixed!(1e20));
+            if let Ok(quantized) = x.checked_quantize(step, RoundingMode::HalfUp) {
+                assert!((quantized - x).unsigned_abs() <= step.unsigned_abs() / fixed!(2e18));
+                assert_eq!(quantized.raw() % step.raw(), I256::zero());
+            }
+        }
     }
 
-    pub fn pow(self, y: Self) -> Result<Self> {
-        let one = self.one();
+    #[test]
+    fn test_pow_int() -> Result<()> {
+        // Exact powers aren't rounded, and negative bases alternate signs.
+        assert_eq!(
+            fixed_u256!(1.5e18).pow_int(3, RoundingMode::Ceil)?,
+            fixed!(3.375e18)
+        );
+        assert_eq!(
+            fixed_i256!(-2e18).pow_int(3, RoundingMode::Ceil)?,
+            fixed!(-8e18)
+        );
+        assert_eq!(
+            fixed_i256!(-2e18).pow_int(4, RoundingMode::Floor)?,
+            fixed!(16e18)
+        );
+        assert_eq!(
+            fixed_i256!(-2e18).pow_int(-2, RoundingMode::Floor)?,
+            fixed!(0.25e18)
+        );
+        assert_eq!(
+            fixed_i256!(-0.5e18).pow_int(-3, RoundingMode::Floor)?,
+            fixed!(-8e18)
+        );
+        assert_eq!(
+            fixed_i256!(0).pow_int(0, RoundingMode::Floor)?,
+            fixed!(1e18)
+        );
+        assert_eq!(fixed_i256!(0).pow_int(5, RoundingMode::Floor)?, fixed!(0));
+        assert_eq!(
+            fixed_u256!(7e18).pow_int(1, RoundingMode::Floor)?,
+            fixed!(7e18)
+        );
+        assert_eq!(
+            fixed_u256!(10e18).pow_int(40, RoundingMode::Floor)?,
+            fixed!(1e58)
+        );
 
-        // If the exponent is negative, return 1 / x^abs(y).
-        if y.is_negative() {
-            let abs_result = self.pow(y.abs())?;
+        // Inexact powers are rounded in the direction of the mode, including
+        // for negative results.
+        let third = fixed_i256!(1e18).div_down(fixed!(3e18));
+        assert_eq!(
+            third.pow_int(2, RoundingMode::Floor)?,
+            fixed!(0.111111111111111110e18)
+        );
+        assert_eq!(
+            third.pow_int(2, RoundingMode::Ceil)?,
+            fixed!(0.111111111111111111e18)
+        );
+        assert_eq!(
+            (-third).pow_int(3, RoundingMode::Floor)?,
+            fixed!(-0.037037037037037037e18)
+        );
+        assert_eq!(
+            (-third).pow_int(3, RoundingMode::Ceil)?,
+            fixed!(-0.037037037037037036e18)
+        );
+        assert_eq!(
+            fixed_i256!(-3e18).pow_int(-1, RoundingMode::Floor)?,
+            fixed!(-0.333333333333333334e18)
+        );
+        assert_eq!(
+            fixed_i256!(-3e18).pow_int(-1, RoundingMode::TowardZero)?,
+            fixed!(-0.333333333333333333e18)
+        );
+        let x = fixed_u256!(1.000000001e18);
+        let power: FixedPoint<U256> = "1.001000500166207841".parse()?;
+        assert!(x.pow_int(1_000_000, RoundingMode::Floor)? <= power);
+        assert!(x.pow_int(1_000_000, RoundingMode::Ceil)? > power);
+        assert_eq!(
+            FixedPoint::<u32, 9>::new(1_100_000_000_u32).pow_int(3, RoundingMode::Floor)?,
+            FixedPoint::new(1_331_000_000_u32)
+        );
 
-            if abs_result.is_zero() {
-                bail!("Cannot divide by zero.");
-            }
+        // Overflows and divisions by zero are errors.
+        assert_eq!(
+            fixed_u256!(10e18).pow_int(60, RoundingMode::Floor),
+            Err(FixedPointError::overflow(
+                Operation::Pow,
+                &["10.000000000000000000", "60"]
+            ))
+        );
+        assert!(matches!(
+            fixed_i256!(-10e18).pow_int(59, RoundingMode::Floor),
+            Err(FixedPointError::Overflow { .. })
+        ));
+        assert!(matches!(
+            fixed_i256!(0).pow_int(-1, RoundingMode::Floor),
+            Err(FixedPointError::DivisionByZero { .. })
+        ));
+        assert!(matches!(
+            fixed_u256!(0.000000001e18).pow_int(-3, RoundingMode::Ceil),
+            Err(FixedPointError::DivisionByZero { .. })
+        ));
 
-            return Ok(one.div_down(abs_result));
-        }
+        Ok(())
+    }
 
-        // If the exponent is 0, return 1.
-        if y.is_zero() {
-            return Ok(one);
+    #[test]
+    fn fuzz_pow_int() -> Result<()> {
+        // Check the directed modes against the exact power in a wide integer.
+        let mut rng = thread_rng();
+        let scale = U1024::exp10(18);
+        for _ in 0..1_000 {
+            let x: FixedPoint<U256> = rng.gen_range(fixed!(0)..fixed!(10e18));
+            let n = rng.gen_range(1..16_u32);
+            let power = (0..n).fold(U1024::one(), |power, _| {
+                power * U1024::from(U512::from(x.raw()))
+            });
+            let (floor, remainder) = power.div_mod(scale.pow((n - 1).into()));
+            let ceil = if remainder.is_zero() {
+                floor
+            } else {
+                floor + 1
+            };
+
+            let down = U1024::from(U512::from(x.pow_int(n as i32, RoundingMode::Floor)?.raw()));
+            let up = U1024::from(U512::from(x.pow_int(n as i32, RoundingMode::Ceil)?.raw()));
+            assert!(down <= floor && up >= ceil, "{x} ^ {n}");
+            // The products' errors compound with the size of the power.
+            let tolerance = U1024::from(2 * n) * (ceil / scale + 1);
+            assert!(up - down <= tolerance, "{x} ^ {n}");
         }
 
-        // If the base is 0, return 0.
-        if self.is_zero() {
-            return Ok(Self::zero());
-        }
+        Ok(())
+    }
 
-        // Using properties of logarithms we calculate x^y: -> ln(x^y) = y *
-        // ln(x) -> e^(y * ln(x)) = x^y
-        let y_int256 = y.to_i256()?;
+    #[test]
+    fn test_roots() -> Result<()> {
+        // Exact roots aren't rounded.
+        assert_eq!(fixed_u256!(4e18).sqrt_down()?, fixed!(2e18));
+        assert_eq!(fixed_u256!(4e18).sqrt_up()?, fixed!(2e18));
+        assert_eq!(fixed_i256!(0.25e18).sqrt_up()?, fixed!(0.5e18));
+        assert_eq!(fixed_i256!(0).sqrt_up()?, fixed!(0));
+        assert_eq!(fixed_u256!(8e18).cbrt(RoundingMode::Ceil)?, fixed!(2e18));
+        assert_eq!(
+            fixed_i128!(0.001e18).cbrt(RoundingMode::Ceil)?,
+            fixed!(0.1e18)
+        );
+        assert_eq!(
+            fixed_u256!(1.5e18).nth_root(1, RoundingMode::Ceil)?,
+            fixed!(1.5e18)
+        );
 
-        // Compute y*ln(x) Any overflow for x will be caught in _ln() in the
-        // initial bounds check
-        let lnx = ln(self.to_i256()?)?;
-        let mut ylnx = y_int256.wrapping_mul(lnx);
-        ylnx = ylnx.wrapping_div(one.to_i256()?);
+        // Inexact roots are rounded in the given direction.
+        assert_eq!(
+            fixed_u256!(2e18).sqrt_down()?,
+            fixed!(1.414213562373095048e18)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).sqrt_up()?,
+            fixed!(1.414213562373095049e18)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).sqrt(RoundingMode::HalfEven)?,
+            fixed!(1.414213562373095049e18)
+        );
+        assert_eq!(
+            fixed_u256!(3e18).sqrt(RoundingMode::HalfUp)?,
+            fixed!(1.732050807568877294e18)
+        );
+        // Roots are never ties, so the nearest modes agree.
+        for rounding in [
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+            RoundingMode::HalfCeil,
+            RoundingMode::HalfFloor,
+        ] {
+            assert_eq!(
+                fixed_u256!(3e18).sqrt(rounding)?,
+                fixed!(1.732050807568877294e18)
+            );
+        }
+        assert_eq!(
+            fixed_u256!(3e18).sqrt_up()?,
+            fixed!(1.732050807568877294e18)
+        );
+        assert_eq!(
+            fixed_u256!(3e18).sqrt_down()?,
+            fixed!(1.732050807568877293e18)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).cbrt(RoundingMode::Floor)?,
+            fixed!(1.259921049894873164e18)
+        );
+        assert_eq!(
+            FixedPoint::<U256>::MAX.nth_root(13, RoundingMode::HalfEven)?,
+            fixed!(34_942.977986525602043544e18)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).nth_root(12, RoundingMode::HalfEven)?,
+            fixed!(1.059463094359295265e18)
+        );
 
-        // Calculate exp(y * ln(x)) to get x^y
-        let (sign, abs) = exp(ylnx)?.into_sign_and_abs();
-        Self::from_sign_and_abs(sign.into(), abs)
-    }
-}
+        // The bounds and other value types don't overflow.
+        assert_eq!(
+            FixedPoint::<U256>::MAX.sqrt_down()?.to_string(),
+            "340282366920938463463374607431.768211455999999999"
+        );
+        assert_eq!(
+            FixedPoint::<U512>::MAX.sqrt(RoundingMode::HalfEven)?,
+            FixedPoint::new(U512::MAX).sqrt_up()?
+        );
+        assert_eq!(
+            FixedPoint::<u32, 9>::new(2_u32).sqrt_up()?,
+            FixedPoint::new(44_722_u32)
+        );
+        assert_eq!(
+            FixedPoint::<i128>::MAX
+                .cbrt(RoundingMode::Floor)?
+                .to_string(),
+            "5541191.377756635628281025"
+        );
 
-impl<T: FixedPointValue> Neg for FixedPoint<T> {
-    type Output = Self;
+        // Invalid inputs are errors.
+        assert!(matches!(
+            fixed_i256!(-4e18).sqrt_down(),
+            Err(FixedPointError::NegativeRoot { .. })
+        ));
+        assert!(matches!(
+            fixed_i256!(4e18).nth_root(0, RoundingMode::Floor),
+            Err(FixedPointError::DivisionByZero {
+                operation: Operation::Root(0),
+                ..
+            })
+        ));
+        assert!(matches!(
+            fixed_u256!(1e18).nth_root(100, RoundingMode::Floor),
+            Err(FixedPointError::Overflow { .. })
+        ));
+        assert!(matches!(
+            FixedPoint::<U512, 154>::MAX.cbrt(RoundingMode::Floor),
+            Err(FixedPointError::Overflow {
+                operation: Operation::Root(3),
+                ..
+            })
+        ));
 
-    fn neg(self) -> Self {
-        Self::new(self.raw().flip_sign())
+        Ok(())
     }
-}
 
-/// Takes a mapping of operator traits to `FixedPoint` methods and implements
-/// the operator and assignment operator for each one.
-macro_rules! mapped_operator_impls {
-    ($($trait:ident => $fn:ident),*) => {
-        $(
-            paste::paste! {
+    #[test]
+    fn fuzz_roots() -> Result<()> {
+        // Check the roots against their definition with wide arithmetic.
+        let mut rng = thread_rng();
+        let one = U512::from(10).pow(18.into());
+        for _ in 0..1_000 {
+            let x: FixedPoint<U256> = rng.gen();
+            let radicand = U512::from(x.raw()) * one;
+            let down = U512::from(x.sqrt_down()?.raw());
+            let up = U512::from(x.sqrt_up()?.raw());
+            assert!(down * down <= radicand && radicand < (down + 1) * (down + 1));
+            assert!(up * up >= radicand && (up == down || up == down + 1));
+            let nearest = U512::from(x.sqrt(RoundingMode::HalfEven)?.raw());
+            let midpoint = (down * 2 + 1) * (down * 2 + 1);
+            assert_eq!(
+                nearest,
+                if radicand * 4 > midpoint {
+                    down + 1
+                } else {
+                    down
+                }
+            );
 
-                impl<T: FixedPointValue> std::ops::$trait for FixedPoint<T> {
-                    type Output = Self;
+            let x: FixedPoint<U256> = rng.gen_range(fixed!(0)..fixed!(1e40));
+            let radicand = U512::from(x.raw()) * one * one;
+            let down = U512::from(x.cbrt(RoundingMode::Floor)?.raw());
+            assert!(down.pow(3.into()) <= radicand && radicand < (down + 1).pow(3.into()));
+        }
 
-                    fn [<$trait:lower>](self, other: Self) -> Self::Output {
-                        self.$fn(other)
-                    }
-                }
+        Ok(())
+    }
 
-                impl<T: FixedPointValue> std::ops::[<$trait Assign>] for FixedPoint<T> {
-                    fn [<$trait:lower _assign>](&mut self, other: Self) {
-                        *self = self.[<$trait:lower>](other);
-                    }
-                }
-            }
-        )*
-    };
-    ($($tt:tt)*) => {};
-}
+    #[test]
+    fn test_ln_exp() -> Result<()> {
+        // Powers of the base are exact.
+        assert_eq!(fixed_i256!(1e18).ln()?, fixed!(0));
+        assert_eq!(fixed_u256!(1_000e18).log10()?, fixed!(3e18));
+        assert_eq!(fixed_i256!(0.001e18).log10()?, fixed!(-3e18));
+        assert_eq!(fixed_u256!(1_024e18).log2()?, fixed!(10e18));
+        assert_eq!(fixed_i256!(0.25e18).log2()?, fixed!(-2e18));
+        assert_eq!(fixed_u256!(100e18).log(fixed!(10e18))?, fixed!(2e18));
+        assert_eq!(fixed_i256!(0).exp()?, fixed!(1e18));
+        assert_eq!(
+            fixed_u256!(64e18).exp2()?,
+            fixed!(18_446_744_073_709_551_616e18)
+        );
+        assert_eq!(fixed_i256!(-3e18).exp2()?, fixed!(0.125e18));
+
+        // Other values are within the documented error bounds, so they're
+        // usually correctly rounded at 18 decimals.
+        assert_eq!(fixed_u256!(2e18).ln()?, fixed!(0.693147180559945309e18));
+        assert_eq!(fixed_u256!(1e18).exp()?, fixed!(2.718281828459045235e18));
+        assert_eq!(fixed_i256!(-1e18).exp()?, fixed!(0.367879441171442322e18));
+        assert_eq!(fixed_u256!(0.5e18).exp2()?, fixed!(1.414213562373095049e18));
+        assert_eq!(fixed_u256!(3e18).log2()?, fixed!(1.584962500721156181e18));
+
+        // The value's decimals are respected, and the result is rounded to
+        // them.
+        let tiny = FixedPoint::<I256, 30>::new(I256::from(1_000));
+        let expected = "-62.169797510839233468485769276478".parse::<FixedPoint<I256, 30>>()?;
+        assert!(tiny.ln()?.abs_diff(expected) <= fixed!(0.000000000002e30));
+        let expected = "0.000000000000093576229688401746".parse::<FixedPoint<I256, 30>>()?;
+        let exp = "-30".parse::<FixedPoint<I256, 30>>()?.exp()?;
+        assert!(exp.abs_diff(expected) <= FixedPoint::new(1_u8));
+        assert_eq!(
+            FixedPoint::<u32, 9>::new(1_000_000_000_u32).exp()?,
+            FixedPoint::new(2_718_281_828_u32)
+        );
+        let expected = "26881171418161354484126255515800135873611118.773741922415191609"
+            .parse::<FixedPoint<U256>>()?;
+        assert!(fixed_u256!(100e18).exp()?.abs_diff(expected) <= fixed!(1e44));
+
+        // Results too small to represent are zero.
+        assert_eq!(fixed_i256!(-50e18).exp()?, fixed!(0));
+        assert_eq!(fixed_i256!(-1e30).exp()?, fixed!(0));
+
+        // Invalid inputs and results that don't fit are errors.
+        assert!(matches!(
+            fixed_i256!(0).ln(),
+            Err(FixedPointError::LnNonPositive { .. })
+        ));
+        assert!(matches!(
+            fixed_i256!(2e18).log(fixed!(-2e18)),
+            Err(FixedPointError::LnNonPositive { .. })
+        ));
+        assert!(matches!(
+            fixed_u256!(0.5e18).ln(),
+            Err(FixedPointError::NegativeToUnsigned { .. })
+        ));
+        assert_eq!(
+            fixed_u256!(2e18).log(fixed!(1e18)),
+            Err(FixedPointError::division_by_zero(
+                Operation::Log,
+                &["2.000000000000000000", "1.000000000000000000"]
+            ))
+        );
+        assert!(matches!(
+            fixed_u256!(200e18).exp(),
+            Err(FixedPointError::ExpOverflow { .. })
+        ));
+        assert!(matches!(
+            fixed_i256!(1_024e18).exp2(),
+            Err(FixedPointError::ExpOverflow { .. })
+        ));
 
-mapped_operator_impls!(
-    // use `mul_down` for `*` and `*=`.
-    Mul => mul_down,
-    // use `div_down` for `/` and `/=`.
-    Div => div_down
-);
+        Ok(())
+    }
 
-/// Takes a list of operator traits and implements the operator and assignment
-/// operator for each one by forwarding to the corresponding method on the
-/// underlying `FixedPointValue`.
-macro_rules! forwarded_operator_impls {
-    ($($trait:ident),*) => {
-        $(
-            paste::paste! {
+    #[test]
+    fn fuzz_ln_exp() -> Result<()> {
+        // Check against floats and each other.
+        let mut rng = thread_rng();
+        for _ in 0..1_000 {
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(-40e18)..fixed!(40e18));
+            let exp = x.exp()?;
+            let float = x.to_f64().exp();
+            assert!(
+                (exp.to_f64() - float).abs() <= float * 1e-14 + 1e-18,
+                "exp({x})"
+            );
+            let exp2 = x.exp2()?;
+            let float = x.to_f64().exp2();
+            assert!(
+                (exp2.to_f64() - float).abs() <= float * 1e-14 + 1e-18,
+                "exp2({x})"
+            );
+
+            let x: FixedPoint<U256> = rng.gen_range(fixed!(1e18)..fixed!(1e40));
+            let ln = x.ln()?;
+            let float = x.to_f64().ln();
+            assert!((ln.to_f64() - float).abs() <= float * 1e-14, "ln({x})");
+            let log10 = x.log10()?;
+            assert!((log10.to_f64() - x.to_f64().log10()).abs() <= float * 1e-14);
+
+            // `e^ln(x)` is within the combined error bounds of x.
+            let round_trip = ln.exp()?;
+            let tolerance = x.mul_up(fixed!(0.00000000000000001e18));
+            assert!(round_trip.abs_diff(x) <= FixedPoint::new(U512::from(tolerance.raw())));
+        }
 
-                impl<T: FixedPointValue> std::ops::$trait for FixedPoint<T> {
-                    type Output = Self;
+        Ok(())
+    }
 
-                    fn [<$trait:lower>](self, other: Self) -> Self::Output {
-                        Self::new(self.raw().[<$trait:lower>](other.raw()))
-                    }
-                }
+    #[test]
+    fn test_accuracy() -> Result<()> {
+        // The Solidity-compatible path truncates where the precise one rounds.
+        let x = fixed_i256!(-42e18);
+        assert_eq!(x.exp_with(Accuracy::SolidityCompatible)?, fixed!(0));
+        assert_eq!(x.exp_with(Accuracy::Precise)?, FixedPoint::new(1));
+        let x = fixed_u256!(0.5e18);
+        assert_eq!(
+            x.pow_with(fixed!(2e18), Accuracy::SolidityCompatible)?,
+            x.pow(fixed!(2e18))?
+        );
+        assert_eq!(
+            x.pow_with(fixed!(2e18), Accuracy::Precise)?,
+            fixed!(0.25e18)
+        );
+        assert_eq!(
+            fixed_u256!(100e18).pow_with(fixed!(1.5e18), Accuracy::Precise)?,
+            fixed!(1_000e18)
+        );
+        assert_eq!(
+            fixed_u256!(2e18).pow_with(fixed!(0.5e18), Accuracy::Precise)?,
+            fixed!(1.414213562373095049e18)
+        );
 
-                impl<T: FixedPointValue> std::ops::[<$trait Assign>] for FixedPoint<T> {
-                    fn [<$trait:lower _assign>](&mut self, other: Self) {
-                        *self = self.[<$trait:lower>](other);
-                    }
-                }
-            }
-        )*
-    };
-    ($($tt:tt)*) => {};
-}
+        // The precise path keeps the value's decimals instead of truncating
+        // to 18.
+        let x = "0.000000000000000000001234".parse::<FixedPoint<I256, 24>>()?;
+        assert!(x.ln_with(Accuracy::SolidityCompatible).is_err());
+        assert_eq!(
+            x.ln_with(Accuracy::Precise)?,
+            "-48.144026027391763293016991".parse()?
+        );
 
-// Forward these operators to the underlying `FixedPointValue`.
-forwarded_operator_impls!(Add, Sub, Rem);
+        // Edge cases of the precise pow.
+        assert_eq!(
+            fixed_u256!(0).pow_with(fixed!(0), Accuracy::Precise)?,
+            fixed!(1e18)
+        );
+        assert_eq!(
+            fixed_u256!(0).pow_with(fixed!(2e18), Accuracy::Precise)?,
+            fixed!(0)
+        );
+        assert!(matches!(
+            fixed_i256!(0).pow_with(fixed!(-2e18), Accuracy::Precise),
+            Err(FixedPointError::DivisionByZero { .. })
+        ));
+        assert!(matches!(
+            fixed_i256!(-2e18).pow_with(fixed!(0.5e18), Accuracy::Precise),
+            Err(FixedPointError::NonRealPower { .. })
+        ));
+        assert!(matches!(
+            fixed_u256!(1_000e18).pow_with(fixed!(100e18), Accuracy::Precise),
+            Err(FixedPointError::Overflow { .. })
+        ));
+
+        // Logarithms with more decimals than the extended intermediates can hold
+        // are errors.
+        fn assert_overflows<const D: u8>(x: FixedPoint<U512, D>) {
+            for result in [
+                x.ln(),
+                x.log2(),
+                x.log10(),
+                x.log(fixed!(2)),
+                x.pow_with(x, Accuracy::Precise),
+            ] {
+                assert!(matches!(result, Err(FixedPointError::Overflow { .. })));
+            }
+        }
+        assert_overflows(FixedPoint::<U512, 140>::new(
+            U512::from(3) * U512::exp10(140),
+        ));
+        assert_overflows(FixedPoint::<U512, { U512::MAX_DECIMALS }>::new(3_u64));
 
-#[cfg(test)]
-mod tests {
-    use std::{panic, u128};
+        Ok(())
+    }
 
-    use ethers::{signers::Signer, types::U256};
-    use eyre::Result;
-    use hyperdrive_wrappers::wrappers::mock_fixed_point_math::MockFixedPointMath;
-    use rand::{thread_rng, Rng};
-    use test_utils::{chain::Chain, constants::DEPLOYER};
+    #[test]
+    fn fuzz_accuracy() -> Result<()> {
+        // The reference functions agree with mpmath.
+        let exp = |x: FixedPoint<I256>| {
+            from_reference(
+                FixedPointSign::Positive,
+                reference_exp(x.sign(), to_reference(x)),
+            )
+        };
+        let ln = |x: FixedPoint<I256>| {
+            let (sign, ln) = reference_ln(to_reference(x));
+            from_reference(sign, ln)
+        };
+        assert_eq!(
+            exp(fixed!(1e18))?,
+            "2.718281828459045235360287471352662498".parse()?
+        );
+        assert_eq!(
+            exp(fixed!(-37.5e18))?,
+            "0.000000000000000051755550058018685349".parse()?
+        );
+        assert_eq!(
+            ln(fixed!(2e18))?,
+            "0.693147180559945309417232121458176568".parse()?
+        );
+        assert_eq!(
+            ln(fixed!(1))?,
+            "-41.446531673892822312323846184318555737".parse()?
+        );
+        assert_eq!(
+            reference_pow(fixed!(123.456e18), fixed!(-4.321e18))?,
+            "0.000000000917435997908437668002829515".parse()?
+        );
 
-    use super::*;
-    use crate::{fixed, fixed_u128, fixed_u256, uint256};
+        // The precise results are within 1 unit of the reference.
+        let within_ulp = |actual: FixedPoint<I256>, reference: FixedPoint<I256, 36>| {
+            let reference: FixedPoint<I256> = reference.rescale(RoundingMode::HalfEven)?;
+            Ok::<_, FixedPointError>(actual.abs_diff(reference) <= FixedPoint::new(1))
+        };
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(-40e18)..fixed!(40e18));
+            assert!(within_ulp(x.exp()?, exp(x)?)?, "exp({x})");
+
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(1)..fixed!(1e30));
+            assert!(within_ulp(x.ln()?, ln(x)?)?, "ln({x})");
+
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(0.01e18)..fixed!(100e18));
+            let y: FixedPoint<I256> = rng.gen_range(fixed!(-5e18)..fixed!(5e18));
+            assert!(
+                within_ulp(x.pow_with(y, Accuracy::Precise)?, reference_pow(x, y)?)?,
+                "pow({x}, {y})"
+            );
+        }
 
-    /// The maximum number that can be divided by another in the Solidity
-    /// implementation.
-    fn max_sol_numerator() -> FixedPoint<U256> {
-        (U256::MAX / uint256!(1e18)).into()
+        Ok(())
     }
 
     #[test]
-    fn test_sub_failure() {
-        // Ensure that subtraction failures are propagated from the raw type.
-        assert!(panic::catch_unwind(|| fixed_u256!(1e18) - fixed!(2e18)).is_err());
+    fn fuzz_wrapping() {
+        // Fuzz the wrapping operations against the EVM's modular arithmetic.
+        let mut rng = thread_rng();
+        let one_u256 = uint256!(1e18);
+        let one_i256 = I256::from_raw(one_u256);
+        for _ in 0..10_000 {
+            let a: FixedPoint<U256> = rng.gen();
+            let b: FixedPoint<U256> = rng.gen();
+            assert_eq!(a.wrapping_add(b).raw(), a.raw().overflowing_add(b.raw()).0);
+            assert_eq!(a.wrapping_sub(b).raw(), a.raw().overflowing_sub(b.raw()).0);
+            assert_eq!(
+                a.wrapping_mul_down(b).raw(),
+                a.raw().overflowing_mul(b.raw()).0 / one_u256
+            );
+            assert_eq!(
+                a.wrapping_div_down(b).raw(),
+                a.raw().overflowing_mul(one_u256).0 / b.raw()
+            );
+
+            let a: FixedPoint<I256> = rng.gen();
+            let b: FixedPoint<I256> = rng.gen();
+            assert_eq!(a.wrapping_add(b).raw(), a.raw().wrapping_add(b.raw()));
+            assert_eq!(a.wrapping_sub(b).raw(), a.raw().wrapping_sub(b.raw()));
+            assert_eq!(
+                a.wrapping_mul_down(b).raw(),
+                a.raw().wrapping_mul(b.raw()).wrapping_div(one_i256)
+            );
+            assert_eq!(
+                a.wrapping_div_down(b).raw(),
+                a.raw().wrapping_mul(one_i256).wrapping_div(b.raw())
+            );
+        }
     }
 
     #[test]
@@ -401,6 +3452,43 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn fuzz_solidity_compatible() -> Result<()> {
+        let chain = Chain::connect(None, None).await?;
+        chain.deal(DEPLOYER.address(), uint256!(100_000e18)).await?;
+        let client = chain.client(DEPLOYER.clone()).await?;
+        let mock_fixed_point_math = MockFixedPointMath::deploy(client, ())?.send().await?;
+
+        // Fuzz the Solidity-compatible methods against the solidity
+        // implementation.
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(-50e18)..fixed!(150e18));
+            let actual = x.exp_with(Accuracy::SolidityCompatible);
+            match mock_fixed_point_math.exp(x.raw()).call().await {
+                Ok(expected) => assert_eq!(actual?, FixedPoint::new(expected)),
+                Err(_) => assert!(actual.is_err()),
+            }
+
+            let x: FixedPoint<I256> = rng.gen_range(fixed!(0)..FixedPoint::MAX);
+            let actual = x.ln_with(Accuracy::SolidityCompatible);
+            match mock_fixed_point_math.ln(x.raw()).call().await {
+                Ok(expected) => assert_eq!(actual?, FixedPoint::new(expected)),
+                Err(_) => assert!(actual.is_err()),
+            }
+
+            let x: FixedPoint<U256> = rng.gen_range(fixed!(0)..=fixed!(10e18));
+            let y: FixedPoint<U256> = rng.gen_range(fixed!(0)..=fixed!(10e18));
+            let actual = x.pow_with(y, Accuracy::SolidityCompatible);
+            match mock_fixed_point_math.pow(x.raw(), y.raw()).call().await {
+                Ok(expected) => assert_eq!(actual?, expected.into()),
+                Err(_) => assert!(actual.is_err()),
+            }
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn fuzz_pow_narrow() -> Result<()> {
         let chain = Chain::connect(None, None).await?;
@@ -411,8 +3499,8 @@ mod tests {
         // Fuzz the rust and solidity implementations against each other.
         let mut rng = thread_rng();
         for _ in 0..10_000 {
-            let x = rng.gen_range(fixed!(0)..=fixed!(1e18));
-            let y = rng.gen_range(fixed!(0)..=fixed!(1e18));
+            let x = rng.gen_range(fixed_u256!(0)..=fixed!(1e18));
+            let y = rng.gen_range(fixed_u256!(0)..=fixed!(1e18));
             let actual = x.pow(y);
             match mock_fixed_point_math.pow(x.raw(), y.raw()).call().await {
                 Ok(expected) => {
@@ -0,0 +1,368 @@
+//! The parser behind `FromStr for FixedPoint`, [`FixedPoint::from_dec_str`],
+//! and the `*_from_str` functions.
+//!
+//! The accepted grammar is:
+//!
+//! ```text
+//! number   = sign? (radix | decimal)
+//! sign     = "+" | "-"
+//! radix    = ("0x" | "0X") hex-digits
+//!          | ("0b" | "0B") binary-digits
+//!          | ("0o" | "0O") octal-digits
+//! decimal  = digits ("." digits)? (("e" | "E") sign? digits)?
+//! ```
+//!
+//! Each run of digits may contain single `_` separators between its digits,
+//! e.g., `1_000`. Nothing else, including whitespace, is accepted.
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use crate::{
+    precise::MAX_UNBOUNDED_DIGITS, FixedPoint, FixedPointError, FixedPointSign, FixedPointValue,
+    Result, UnsignedValue,
+};
+
+/// Parses a string into the raw value of a `T` scaled by `10^exponent`, e.g.,
+/// `"1.5"` with `exponent = 18` is `1.5e18`. The exponent is usually the
+/// number of decimals, but may be negative for strings written in a larger
+/// unit, e.g., `"50"` with `exponent = -2` is `0.5`, so `"150"` is rejected.
+pub(crate) fn parse<T: FixedPointValue>(s: &str, exponent: i64) -> Result<T> {
+    let mut parser = Parser { input: s, pos: 0 };
+
+    let sign = if parser.eat(b'-') {
+        FixedPointSign::Negative
+    } else {
+        parser.eat(b'+');
+        FixedPointSign::Positive
+    };
+
+    let radix = match s.as_bytes()[parser.pos..] {
+        [b'0', b'x' | b'X', ..] => 16,
+        [b'0', b'b' | b'B', ..] => 2,
+        [b'0', b'o' | b'O', ..] => 8,
+        _ => 10,
+    };
+    let mut digits = if radix == 10 {
+        parser.digits(10, "digits")?
+    } else {
+        parser.pos += 2;
+        parser.digits(radix, "digits after the radix prefix")?
+    };
+
+    // The number of places to shift the digits to the left, i.e., the power of
+    // 10 the digits are multiplied by.
+    let places = exponent;
+    let mut exponent = exponent;
+    if radix == 10 {
+        if parser.eat(b'.') {
+            let fraction = parser.digits(10, "fraction digits")?;
+            exponent -= fraction.len() as i64;
+            digits.extend(fraction);
+        }
+        if parser.eat(b'e') || parser.eat(b'E') {
+            let start = parser.pos;
+            let is_negative = parser.eat(b'-');
+            if !is_negative {
+                parser.eat(b'+');
+            }
+            let value = parser
+                .digits(10, "exponent digits")?
+                .into_iter()
+                .try_fold(0_i64, |value, (_, digit)| {
+                    value
+                        .checked_mul(10)?
+                        .checked_add(digit.into())
+                        .filter(|&value| value <= u32::MAX.into())
+                })
+                .ok_or_else(|| FixedPointError::parse(s, start, "exponent is too large"))?;
+            exponent += if is_negative { -value } else { value };
+        }
+    }
+
+    if let Some(c) = parser.peek_char() {
+        return Err(FixedPointError::parse(
+            s,
+            parser.pos,
+            format!("unexpected character {c:?}"),
+        ));
+    }
+
+    // Digits shifted past the decimal point must be zeros.
+    if exponent < 0 {
+        let keep = digits
+            .len()
+            .saturating_sub(exponent.unsigned_abs() as usize);
+        if let Some(&(position, _)) = digits[keep..].iter().find(|&&(_, digit)| digit != 0) {
+            let reason = match places {
+                0.. => format!("more than {places} decimal places"),
+                _ => format!("not a multiple of 1e{}", -places),
+            };
+            return Err(FixedPointError::parse(s, position, reason));
+        }
+        digits.truncate(keep);
+        exponent = 0;
+    }
+
+    // Accumulate the digits into little-endian `u64` limbs, bailing out early
+    // once the value can't fit in the unsigned type.
+    let out_of_range = || FixedPointError::OutOfRange {
+        value: s.to_string(),
+        target: core::any::type_name::<T>(),
+    };
+    let max_limbs = T::Unsigned::MAX.map(|max| max.into_limbs().len());
+    let mut limbs = Vec::new();
+    let mut shift_in = |mul: u64, add: u64| {
+        mul_add(&mut limbs, mul, add);
+        match max_limbs {
+            Some(max_limbs) if limbs.len() > max_limbs => Err(out_of_range()),
+            _ => Ok(()),
+        }
+    };
+    let mut is_zero = true;
+    for (_, digit) in digits {
+        is_zero &= digit == 0;
+        shift_in(radix.into(), digit.into())?;
+    }
+    if !is_zero {
+        // A non-zero value is at least `10^exponent`, and a limb holds fewer
+        // than 20 digits, so larger exponents can't fit.
+        let max_exponent = max_limbs.map_or(MAX_UNBOUNDED_DIGITS, |max_limbs| max_limbs * 20);
+        let mut exponent = usize::try_from(exponent)
+            .ok()
+            .filter(|&exponent| exponent <= max_exponent)
+            .ok_or_else(out_of_range)?;
+        while exponent > 0 {
+            let n = exponent.min(19);
+            shift_in(10_u64.pow(n as u32), 0)?;
+            exponent -= n;
+        }
+    }
+
+    let abs = T::Unsigned::from_limbs(&limbs).ok_or_else(out_of_range)?;
+    Ok(FixedPoint::<T, 0>::from_sign_and_abs(sign, abs)?.raw())
+}
+
+/// Computes `limbs * mul + add` in place, where `limbs` are the little-endian
+/// `u64` limbs of an unsigned integer.
+fn mul_add(limbs: &mut Vec<u64>, mul: u64, add: u64) {
+    let mut carry = u128::from(add);
+    for limb in limbs.iter_mut() {
+        let value = u128::from(*limb) * u128::from(mul) + carry;
+        *limb = value as u64;
+        carry = value >> 64;
+    }
+    if carry != 0 {
+        limbs.push(carry as u64);
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    /// The byte offset of the next character.
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn peek_char(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    /// Consumes the next byte if it's equal to `byte`.
+    fn eat(&mut self, byte: u8) -> bool {
+        if self.input.as_bytes().get(self.pos) == Some(&byte) {
+            self.pos += 1;
+            return true;
+        }
+        false
+    }
+
+    /// Describes the next character for an error message.
+    fn found(&self) -> String {
+        match self.peek_char() {
+            Some(c) => format!("{c:?}"),
+            None => "end of input".to_string(),
+        }
+    }
+
+    /// Consumes a non-empty run of digits in the given radix, returning the
+    /// value and byte offset of each digit. Each `_` separator must be between
+    /// two digits.
+    fn digits(&mut self, radix: u32, expected: &str) -> Result<Vec<(usize, u8)>> {
+        let mut digits = Vec::new();
+        while let Some(c) = self.peek_char() {
+            if let Some(digit) = c.to_digit(radix) {
+                digits.push((self.pos, digit as u8));
+            } else if c == '_' && !digits.is_empty() {
+                self.pos += 1;
+                if !self.peek_char().is_some_and(|c| c.is_digit(radix)) {
+                    return Err(FixedPointError::parse(
+                        self.input,
+                        self.pos,
+                        format!("expected a digit after '_', found {}", self.found()),
+                    ));
+                }
+                continue;
+            } else {
+                break;
+            }
+            self.pos += 1;
+        }
+        if digits.is_empty() {
+            return Err(FixedPointError::parse(
+                self.input,
+                self.pos,
+                format!("expected {expected}, found {}", self.found()),
+            ));
+        }
+        Ok(digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+
+    use super::*;
+    use crate::{fixed, int256, uint256};
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        // Signs, separators, fractions, and exponents.
+        assert_eq!(parse::<I256>("-1_000", 0)?, int256!(-1_000));
+        assert_eq!(parse::<I256>("+1.5", 18)?, int256!(1.5e18));
+        assert_eq!(parse::<U256>("1.5E3", 0)?, uint256!(1_500));
+        assert_eq!(parse::<U256>("15e-1", 18)?, uint256!(1.5e18));
+        assert_eq!(parse::<U256>("1.500", 1)?, uint256!(15));
+        assert_eq!(parse::<u128>("0.0e999999", 18)?, 0);
+        assert_eq!(parse::<i128>("-0", 0)?, 0);
+        assert_eq!(parse::<U256>("1_500", -2)?, uint256!(15));
+
+        // Radix prefixes.
+        assert_eq!(parse::<U256>("0xff", 0)?, uint256!(255));
+        assert_eq!(parse::<U256>("0XDEAD_beef", 0)?, uint256!(0xdeadbeef));
+        assert_eq!(parse::<I256>("-0b1010", 0)?, int256!(-10));
+        assert_eq!(parse::<u64>("0o17", 2)?, 1_500);
+        assert_eq!(
+            parse::<U256>(&format!("0x{}", "f".repeat(64)), 0)?,
+            U256::MAX
+        );
+
+        // Bounds.
+        assert_eq!(parse::<I256>(&I256::MIN.to_string(), 0)?, I256::MIN);
+        assert!(matches!(
+            parse::<U256>(&format!("0x1{}", "0".repeat(64)), 0),
+            Err(FixedPointError::OutOfRange { .. })
+        ));
+        assert!(matches!(
+            parse::<u32>("1e4294967295", 0),
+            Err(FixedPointError::OutOfRange { .. })
+        ));
+        for s in ["1e4294967295", "1e4294967290", "0.1e4294967295"] {
+            assert!(matches!(
+                parse::<U256>(s, 18),
+                Err(FixedPointError::OutOfRange { .. })
+            ));
+            assert!(matches!(
+                s.parse::<FixedPoint<u128>>(),
+                Err(FixedPointError::OutOfRange { .. })
+            ));
+        }
+        assert!(matches!(
+            parse::<U256>("-1", 0),
+            Err(FixedPointError::NegativeToUnsigned { .. })
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |s: &str| match parse::<I256>(s, 0) {
+            Err(FixedPointError::Parse {
+                position, reason, ..
+            }) => (position, reason),
+            result => panic!("expected a parse error for {s:?}, got {result:?}"),
+        };
+        assert_eq!(error(""), (0, "expected digits, found end of input".into()));
+        assert_eq!(
+            error("-"),
+            (1, "expected digits, found end of input".into())
+        );
+        assert_eq!(error("1-2"), (1, "unexpected character '-'".into()));
+        assert_eq!(
+            error("1e"),
+            (2, "expected exponent digits, found end of input".into())
+        );
+        assert_eq!(
+            error("1e+"),
+            (3, "expected exponent digits, found end of input".into())
+        );
+        assert_eq!(
+            error("1."),
+            (2, "expected fraction digits, found end of input".into())
+        );
+        assert_eq!(error(".5"), (0, "expected digits, found '.'".into()));
+        assert_eq!(error("_1"), (0, "expected digits, found '_'".into()));
+        assert_eq!(
+            error("1_"),
+            (2, "expected a digit after '_', found end of input".into())
+        );
+        assert_eq!(
+            error("1__0"),
+            (2, "expected a digit after '_', found '_'".into())
+        );
+        assert_eq!(
+            error("1_.5"),
+            (2, "expected a digit after '_', found '.'".into())
+        );
+        assert_eq!(
+            error("1e1_"),
+            (4, "expected a digit after '_', found end of input".into())
+        );
+        assert_eq!(
+            error("0x_f"),
+            (
+                2,
+                "expected digits after the radix prefix, found '_'".into()
+            )
+        );
+        assert_eq!(error("1.5"), (2, "more than 0 decimal places".into()));
+        assert_eq!(error("1.25e1"), (3, "more than 0 decimal places".into()));
+        assert_eq!(error("1e99999999999"), (2, "exponent is too large".into()));
+        assert_eq!(
+            parse::<U256>("150", -2),
+            Err(FixedPointError::parse("150", 1, "not a multiple of 1e2"))
+        );
+        assert_eq!(
+            error("0x"),
+            (
+                2,
+                "expected digits after the radix prefix, found end of input".into()
+            )
+        );
+        assert_eq!(error("0xfg"), (3, "unexpected character 'g'".into()));
+        assert_eq!(error("0x1.5"), (3, "unexpected character '.'".into()));
+        assert_eq!(error("0b12"), (3, "unexpected character '2'".into()));
+        assert_eq!(error(" 1"), (0, "expected digits, found ' '".into()));
+        assert_eq!(error("1 "), (1, "unexpected character ' '".into()));
+        assert_eq!(error("1€"), (1, "unexpected character '€'".into()));
+
+        assert_eq!(
+            parse::<U256>("1.5x", 0).unwrap_err().to_string(),
+            r#"Failed to parse "1.5x" at byte 3: unexpected character 'x'"#
+        );
+        assert_eq!(
+            "1.0000000000000000001".parse::<FixedPoint<U256>>(),
+            Err(FixedPointError::parse(
+                "1.0000000000000000001",
+                20,
+                "more than 18 decimal places"
+            ))
+        );
+        assert_eq!("0.5".parse::<FixedPoint<U256>>(), Ok(fixed!(0.5e18)));
+    }
+}
@@ -0,0 +1,149 @@
+//! Logarithms and exponentials computed to arbitrary precision with wide
+//! integers, which back [`Accuracy::Precise`](crate::Accuracy::Precise).
+//!
+//! Values are fixed point numbers scaled by `10^precision` for a working
+//! precision chosen by the caller. Each function truncates its series terms,
+//! so its result is within a few hundred units of `10^-precision`, which the
+//! callers cover by computing [`GUARD_DIGITS`] more digits than they round to.
+
+use crate::wide::WideValue;
+
+/// The number of digits computed beyond the last digit of a result so that
+/// the truncation errors of the series can't change how it's rounded.
+pub(crate) const GUARD_DIGITS: usize = 20;
+
+/// The most digits a result of an unbounded type can have before it's treated
+/// as an overflow, which bounds the time spent on the series and on parsing.
+pub(crate) const MAX_UNBOUNDED_DIGITS: usize = 10_000;
+
+/// Computes `10^n`, returning `None` if it overflows.
+pub(crate) fn checked_exp10<W: WideValue>(n: usize) -> Option<W> {
+    (0..n / 19).try_fold(W::exp10(n % 19), |power, _| power.checked_mul(W::exp10(19)))
+}
+
+/// Returns whether products of the values in this module fit in `W` at the
+/// given precision. The values are below `10^(precision + 4)`.
+pub(crate) fn fits<W: WideValue>(precision: usize) -> bool {
+    checked_exp10::<W>(2 * precision + 8).is_some()
+}
+
+/// Computes `ln(2)`.
+pub(crate) fn ln2<W: WideValue>(precision: usize) -> W {
+    // ln(2) = 2 * atanh(1 / 3)
+    atanh_inv::<W>(3, precision) * W::from_u64(2)
+}
+
+/// Computes `ln(10)`.
+pub(crate) fn ln10<W: WideValue>(precision: usize) -> W {
+    // ln(10) = 3 * ln(2) + ln(5 / 4) = 3 * ln(2) + 2 * atanh(1 / 9)
+    ln2::<W>(precision) * W::from_u64(3) + atanh_inv::<W>(9, precision) * W::from_u64(2)
+}
+
+/// Computes `ln(x)` for `x` in `[1, 10)`.
+pub(crate) fn ln_mantissa<W: WideValue>(mut x: W, precision: usize) -> W {
+    let one = W::exp10(precision);
+    let two = W::from_u64(2);
+
+    // Halve `x` into `[0.75, 1.5)`, so that `ln(x) = halvings * ln(2) + 2 *
+    // atanh(z)` with `z = (x - 1) / (x + 1)` in `[-1/7, 1/5)`.
+    let threshold = (one.clone() * W::from_u64(3)).div_mod(two.clone()).0;
+    let mut halvings = 0;
+    while x >= threshold {
+        x = x.div_mod(two.clone()).0;
+        halvings += 1;
+    }
+    let ln_2 = ln2::<W>(precision) * W::from_u64(halvings);
+    if x >= one {
+        let z = ((x.clone() - one.clone()) * one.clone()).div_mod(x + one).0;
+        ln_2 + atanh(z, precision) * two
+    } else {
+        // `x < 1` is only possible after halving, so `ln_2 >= ln(2)` is
+        // greater than `2 * atanh(1/7)`.
+        let z = ((one.clone() - x.clone()) * one.clone()).div_mod(x + one).0;
+        ln_2 - atanh(z, precision) * two
+    }
+}
+
+/// Computes `e^r` for `r` in `[0, ln(10))`.
+pub(crate) fn exp_remainder<W: WideValue>(r: W, precision: usize) -> W {
+    // e^r = sum(r^n / n!)
+    let one = W::exp10(precision);
+    let mut term = one.clone();
+    let mut sum = one.clone();
+    let mut n = 1;
+    loop {
+        term = (term * r.clone()).div_mod(one.clone() * W::from_u64(n)).0;
+        if term.is_zero() {
+            return sum;
+        }
+        sum = sum + term.clone();
+        n += 1;
+    }
+}
+
+/// Computes `atanh(z)` for `z` in `[0, 1/5]` with its Taylor series,
+/// `sum(z^(2i + 1) / (2i + 1))`.
+fn atanh<W: WideValue>(z: W, precision: usize) -> W {
+    let one = W::exp10(precision);
+    let z_squared = (z.clone() * z.clone()).div_mod(one.clone()).0;
+    let mut power = z;
+    let mut sum = W::from_u64(0);
+    let mut i = 0;
+    while !power.is_zero() {
+        sum = sum + power.clone().div_mod(W::from_u64(2 * i + 1)).0;
+        power = (power * z_squared.clone()).div_mod(one.clone()).0;
+        i += 1;
+    }
+    sum
+}
+
+/// Computes `atanh(1 / n)` for `n > 1`, whose series only needs divisions.
+fn atanh_inv<W: WideValue>(n: u64, precision: usize) -> W {
+    let n_squared = W::from_u64(n * n);
+    let mut power = W::exp10(precision).div_mod(W::from_u64(n)).0;
+    let mut sum = W::from_u64(0);
+    let mut i = 0;
+    while !power.is_zero() {
+        sum = sum + power.clone().div_mod(W::from_u64(2 * i + 1)).0;
+        power = power.div_mod(n_squared.clone()).0;
+        i += 1;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wide::U1024;
+
+    #[test]
+    fn test_constants() {
+        // The results are within a few hundred units of the truncated true
+        // values.
+        let assert_close = |actual: U1024, expected: &str| {
+            let expected = U1024::from_dec_str(expected).unwrap();
+            let error = if actual > expected {
+                actual - expected
+            } else {
+                expected - actual
+            };
+            assert!(error < U1024::from(1_000), "{actual} != {expected}");
+        };
+        assert_close(ln2(40), "6931471805599453094172321214581765680755");
+        assert_close(ln10(40), "23025850929940456840179914546843642076011");
+        assert_close(
+            exp_remainder(U1024::exp10(40), 40),
+            "27182818284590452353602874713526624977572",
+        );
+        assert_close(
+            ln_mantissa(U1024::from(3) * U1024::exp10(40), 40),
+            "10986122886681096913952452369225257046475",
+        );
+
+        // Exact inputs are exact.
+        assert_eq!(ln_mantissa(U1024::from(2) * U1024::exp10(40), 40), ln2(40));
+        assert_eq!(ln_mantissa(U1024::exp10(40), 40), U1024::from(0));
+        assert!(fits::<U1024>(150));
+        assert!(!fits::<U1024>(151));
+    }
+}
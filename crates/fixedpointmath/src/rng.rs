@@ -1,3 +1,4 @@
+use primitive_types::U512;
 use rand::{
     distributions::{
         uniform::{SampleBorrow, SampleUniform, UniformSampler},
@@ -6,23 +7,23 @@ use rand::{
     Rng,
 };
 
-use crate::{FixedPoint, FixedPointValue};
+use crate::{FixedPoint, FixedPointValue, DEFAULT_DECIMALS};
 
 // See:
 // https://docs.rs/rand/latest/rand/distributions/uniform/index.html#extending-uniform-to-support-a-custom-type
 
 #[derive(Clone, Copy, Debug)]
-pub struct UniformFixedPoint<T: FixedPointValue> {
-    low: FixedPoint<T>,
-    high: FixedPoint<T>,
+pub struct UniformFixedPoint<T: FixedPointValue, const D: u8 = DEFAULT_DECIMALS> {
+    low: FixedPoint<T, D>,
+    high: FixedPoint<T, D>,
 }
 
-impl<T: FixedPointValue> SampleUniform for FixedPoint<T> {
-    type Sampler = UniformFixedPoint<T>;
+impl<T: FixedPointValue, const D: u8> SampleUniform for FixedPoint<T, D> {
+    type Sampler = UniformFixedPoint<T, D>;
 }
 
-impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
-    type X = FixedPoint<T>;
+impl<T: FixedPointValue, const D: u8> UniformSampler for UniformFixedPoint<T, D> {
+    type X = FixedPoint<T, D>;
 
     #[inline]
     fn new<B1, B2>(low_b: B1, high_b: B2) -> Self
@@ -30,8 +31,8 @@ impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
         B1: SampleBorrow<Self::X> + Sized,
         B2: SampleBorrow<Self::X> + Sized,
     {
-        let low = *low_b.borrow();
-        let high = *high_b.borrow() - FixedPoint::new(1);
+        let low = low_b.borrow().clone();
+        let high = high_b.borrow().clone() - FixedPoint::new(T::from_u128(1).unwrap());
         if low >= high {
             panic!(
                 r#"UniformFixedPoint::new_inclusive called with invalid range:
@@ -48,8 +49,8 @@ impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
         B1: SampleBorrow<Self::X> + Sized,
         B2: SampleBorrow<Self::X> + Sized,
     {
-        let low = *low_b.borrow();
-        let high = *high_b.borrow();
+        let low = low_b.borrow().clone();
+        let high = high_b.borrow().clone();
         if low >= high {
             panic!(
                 r#"UniformFixedPoint::new_inclusive called with invalid range:
@@ -61,28 +62,36 @@ impl<T: FixedPointValue> UniformSampler for UniformFixedPoint<T> {
     }
 
     #[inline]
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T> {
-        let size = self.high.abs_diff(self.low);
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T, D> {
+        let size = self.high.abs_diff(self.low.clone());
         if size.is_zero() {
             panic!("UniformFixedPoint::sample called with size zero.");
         }
-        let value = FixedPoint::new(rng.gen::<[u8; 32]>());
-        let narrowed = value % size;
-        let max = FixedPoint::<T>::MAX;
-        let raw = if narrowed <= max.unsigned_abs() {
-            self.low.raw() + T::from_u256(narrowed.raw()).unwrap()
+        // The random value is narrowed as a `U512` since it may not fit in
+        // `T::Unsigned`. Sizes beyond `U512`, which only unbounded types have,
+        // are already larger than it.
+        let value = U512(rng.gen());
+        let narrowed = match size.raw().to_u512() {
+            Ok(size) => value % size,
+            Err(_) => value,
+        };
+        let narrowed = FixedPoint::<T::Unsigned, D>::new(T::Unsigned::from_u512(narrowed).unwrap());
+        let fits = T::MAX.is_none_or(|max| narrowed.raw() <= max.unsigned_abs());
+        let raw = if fits {
+            self.low.raw() + T::from_unsigned(narrowed.raw()).unwrap()
         } else {
             let abs_low = self.low.unsigned_abs();
             let abs_diff = narrowed.abs_diff(abs_low);
-            T::from_u256(abs_diff.raw()).unwrap()
+            T::from_unsigned(abs_diff.raw()).unwrap()
         };
         FixedPoint::new(raw)
     }
 }
 
-impl<T: FixedPointValue> Distribution<FixedPoint<T>> for Standard {
-    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T> {
-        rng.gen_range(FixedPoint::<T>::MIN..=FixedPoint::<T>::MAX)
+// Values are sampled from the full range of bounded types.
+impl<T: FixedPointValue + Copy, const D: u8> Distribution<FixedPoint<T, D>> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> FixedPoint<T, D> {
+        rng.gen_range(FixedPoint::<T, D>::MIN..=FixedPoint::<T, D>::MAX)
     }
 }
 
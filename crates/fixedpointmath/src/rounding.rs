@@ -0,0 +1,168 @@
+use core::cmp::Ordering;
+
+use crate::{wide::WideValue, FixedPointSign};
+
+/// The rule used to round a result that can't be represented exactly.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round towards negative infinity.
+    Floor,
+    /// Round towards positive infinity.
+    Ceil,
+    /// Round towards zero, i.e., truncate. This is how the `_down` methods
+    /// round.
+    TowardZero,
+    /// Round away from zero. This is how the `_up` methods round.
+    AwayFromZero,
+    /// Round to the nearest value, rounding ties away from zero.
+    HalfUp,
+    /// Round to the nearest value, rounding ties towards zero.
+    HalfDown,
+    /// Round to the nearest value, rounding ties to the even neighbor.
+    HalfEven,
+    /// Round to the nearest value, rounding ties towards positive infinity.
+    HalfCeil,
+    /// Round to the nearest value, rounding ties towards negative infinity.
+    HalfFloor,
+}
+
+impl RoundingMode {
+    /// Rounds the absolute value of a quotient given the sign of the result,
+    /// the remainder, and the absolute value of the divisor.
+    pub(crate) fn round<W: WideValue>(
+        self,
+        sign: FixedPointSign,
+        quotient: W,
+        remainder: W,
+        divisor: W,
+    ) -> W {
+        if remainder.is_zero() {
+            return quotient;
+        }
+        let away_from_zero = match self {
+            RoundingMode::Floor => sign.is_negative(),
+            RoundingMode::Ceil => sign.is_positive(),
+            RoundingMode::TowardZero => false,
+            RoundingMode::AwayFromZero => true,
+            // Compare the remainder to the distance to the next multiple of the
+            // divisor instead of doubling it to avoid overflow.
+            half => match remainder.clone().cmp(&(divisor - remainder)) {
+                Ordering::Less => false,
+                Ordering::Greater => true,
+                Ordering::Equal => match half.for_sign(sign) {
+                    RoundingMode::HalfDown => false,
+                    RoundingMode::HalfEven => quotient.is_odd(),
+                    _ => true,
+                },
+            },
+        };
+        if away_from_zero {
+            quotient + W::one()
+        } else {
+            quotient
+        }
+    }
+
+    /// Returns the mode that rounds an absolute value the same way this mode
+    /// rounds a result with the given sign, e.g., `AwayFromZero` for `Floor`
+    /// and a negative result.
+    pub(crate) fn for_sign(self, sign: FixedPointSign) -> Self {
+        match self {
+            RoundingMode::Floor if sign.is_negative() => RoundingMode::AwayFromZero,
+            RoundingMode::Ceil if sign.is_positive() => RoundingMode::AwayFromZero,
+            RoundingMode::Floor | RoundingMode::Ceil => RoundingMode::TowardZero,
+            RoundingMode::HalfFloor if sign.is_negative() => RoundingMode::HalfUp,
+            RoundingMode::HalfCeil if sign.is_positive() => RoundingMode::HalfUp,
+            RoundingMode::HalfFloor | RoundingMode::HalfCeil => RoundingMode::HalfDown,
+            mode => mode,
+        }
+    }
+
+    /// Returns the mode that rounds in the opposite direction, e.g., for the
+    /// divisor of a reciprocal. Modes that round to the nearest value are
+    /// their own opposite.
+    pub(crate) fn opposite(self) -> Self {
+        match self {
+            RoundingMode::Floor => RoundingMode::Ceil,
+            RoundingMode::Ceil => RoundingMode::Floor,
+            RoundingMode::TowardZero => RoundingMode::AwayFromZero,
+            RoundingMode::AwayFromZero => RoundingMode::TowardZero,
+            mode => mode,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wide::U1024;
+
+    #[test]
+    fn test_round() {
+        let round = |mode: RoundingMode, sign, quotient: u64, remainder: u64| {
+            mode.round(sign, U1024::from(quotient), remainder.into(), 10.into())
+                .as_u64()
+        };
+        let pos = FixedPointSign::Positive;
+        let neg = FixedPointSign::Negative;
+
+        // Exact results are never rounded.
+        for mode in [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::TowardZero,
+            RoundingMode::AwayFromZero,
+            RoundingMode::HalfUp,
+            RoundingMode::HalfDown,
+            RoundingMode::HalfEven,
+            RoundingMode::HalfCeil,
+            RoundingMode::HalfFloor,
+        ] {
+            assert_eq!(round(mode, pos, 2, 0), 2);
+            assert_eq!(round(mode, neg, 2, 0), 2);
+        }
+
+        assert_eq!(round(RoundingMode::Floor, pos, 2, 9), 2);
+        assert_eq!(round(RoundingMode::Floor, neg, 2, 1), 3);
+        assert_eq!(round(RoundingMode::Ceil, pos, 2, 1), 3);
+        assert_eq!(round(RoundingMode::Ceil, neg, 2, 9), 2);
+        assert_eq!(round(RoundingMode::TowardZero, neg, 2, 9), 2);
+        assert_eq!(round(RoundingMode::AwayFromZero, pos, 2, 1), 3);
+
+        assert_eq!(round(RoundingMode::HalfUp, pos, 2, 4), 2);
+        assert_eq!(round(RoundingMode::HalfUp, pos, 2, 5), 3);
+        assert_eq!(round(RoundingMode::HalfUp, neg, 2, 5), 3);
+        assert_eq!(round(RoundingMode::HalfEven, pos, 2, 5), 2);
+        assert_eq!(round(RoundingMode::HalfEven, pos, 3, 5), 4);
+        assert_eq!(round(RoundingMode::HalfEven, neg, 3, 5), 4);
+        assert_eq!(round(RoundingMode::HalfEven, pos, 2, 6), 3);
+        assert_eq!(round(RoundingMode::HalfDown, pos, 2, 5), 2);
+        assert_eq!(round(RoundingMode::HalfDown, neg, 2, 6), 3);
+        assert_eq!(round(RoundingMode::HalfCeil, pos, 2, 5), 3);
+        assert_eq!(round(RoundingMode::HalfCeil, neg, 2, 5), 2);
+        assert_eq!(round(RoundingMode::HalfFloor, pos, 2, 5), 2);
+        assert_eq!(round(RoundingMode::HalfFloor, neg, 2, 5), 3);
+        assert_eq!(round(RoundingMode::HalfFloor, neg, 2, 4), 2);
+    }
+
+    #[test]
+    fn test_directions() {
+        let pos = FixedPointSign::Positive;
+        let neg = FixedPointSign::Negative;
+        assert_eq!(RoundingMode::Floor.for_sign(pos), RoundingMode::TowardZero);
+        assert_eq!(
+            RoundingMode::Floor.for_sign(neg),
+            RoundingMode::AwayFromZero
+        );
+        assert_eq!(RoundingMode::Ceil.for_sign(pos), RoundingMode::AwayFromZero);
+        assert_eq!(RoundingMode::HalfUp.for_sign(neg), RoundingMode::HalfUp);
+        assert_eq!(RoundingMode::HalfCeil.for_sign(neg), RoundingMode::HalfDown);
+        assert_eq!(RoundingMode::HalfFloor.for_sign(neg), RoundingMode::HalfUp);
+        assert_eq!(RoundingMode::Floor.opposite(), RoundingMode::Ceil);
+        assert_eq!(
+            RoundingMode::TowardZero.opposite(),
+            RoundingMode::AwayFromZero
+        );
+        assert_eq!(RoundingMode::HalfEven.opposite(), RoundingMode::HalfEven);
+    }
+}
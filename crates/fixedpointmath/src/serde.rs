@@ -0,0 +1,308 @@
+//! Serde support for `FixedPoint`.
+//!
+//! By default, values are serialized as decimal strings of their scaled value,
+//! e.g., `"1.500000000000000000"`, and deserialized with `FromStr`, e.g., from
+//! `"1.5"` or `"-2.5e3"`. Values outside of `FixedPoint::<T>::MIN..=MAX` or
+//! with more decimal places than the type are rejected.
+//!
+//! The modules in this file select other encodings with
+//! `#[serde(with = "...")]`:
+//!
+//! ```
+//! use ethers::types::U256;
+//! use fixedpointmath::{fixed, FixedPoint};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Pool {
+//!     share_price: FixedPoint<U256>,
+//!     #[serde(with = "fixedpointmath::serde::raw_hex")]
+//!     reserves: FixedPoint<U256>,
+//! }
+//!
+//! let pool = Pool {
+//!     share_price: fixed!(1.5e18),
+//!     reserves: fixed!(255),
+//! };
+//! let json = serde_json::to_string(&pool).unwrap();
+//! assert_eq!(json, r#"{"share_price":"1.500000000000000000","reserves":"0xff"}"#);
+//! ```
+
+use core::{fmt, result::Result};
+
+use ::serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{FixedPoint, FixedPointValue};
+
+impl<T: FixedPointValue, const D: u8> Serialize for FixedPoint<T, D> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, T: FixedPointValue, const D: u8> Deserialize<'de> for FixedPoint<T, D> {
+    fn deserialize<De: Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        deserializer.deserialize_str(StrVisitor(str::parse, "a decimal string"))
+    }
+}
+
+/// Serializes the raw value as a decimal integer string, e.g.,
+/// `"1500000000000000000"` for `1.5` with 18 decimals. Deserialization accepts
+/// the same inputs as [`FixedPoint::from_dec_str`].
+pub mod raw_dec {
+    use super::*;
+
+    pub fn serialize<T, S, const D: u8>(
+        value: &FixedPoint<T, D>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: FixedPointValue,
+        S: Serializer,
+    {
+        serializer.collect_str(&FixedPoint::<T, 0>::new(value.raw()))
+    }
+
+    pub fn deserialize<'de, T, De, const D: u8>(
+        deserializer: De,
+    ) -> Result<FixedPoint<T, D>, De::Error>
+    where
+        T: FixedPointValue,
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor(
+            FixedPoint::from_dec_str,
+            "a raw decimal integer string",
+        ))
+    }
+}
+
+/// Serializes the raw value as a `0x`-prefixed hexadecimal string, e.g.,
+/// `"0x14d1120d7b160000"` for `1.5` with 18 decimals. Negative values are
+/// prefixed with `-`. Deserialization accepts the same inputs as
+/// [`FixedPoint::from_dec_str`].
+pub mod raw_hex {
+    use super::*;
+
+    pub fn serialize<T, S, const D: u8>(
+        value: &FixedPoint<T, D>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: FixedPointValue,
+        S: Serializer,
+    {
+        serializer.collect_str(&format_args!("{value:#x}"))
+    }
+
+    pub fn deserialize<'de, T, De, const D: u8>(
+        deserializer: De,
+    ) -> Result<FixedPoint<T, D>, De::Error>
+    where
+        T: FixedPointValue,
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(StrVisitor(
+            FixedPoint::from_dec_str,
+            "a raw hexadecimal string",
+        ))
+    }
+}
+
+/// Serializes the scaled value as a number, e.g., `1.5`, which loses precision
+/// beyond what an `f64` can represent. Deserialization converts the exact value
+/// of the `f64`, truncating any decimal places beyond the type's decimals.
+pub mod scaled_f64_lossy {
+    use core::marker::PhantomData;
+
+    use super::*;
+    use crate::{FixedPointSign, RoundingMode};
+
+    pub fn serialize<T, S, const D: u8>(
+        value: &FixedPoint<T, D>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        T: FixedPointValue,
+        S: Serializer,
+    {
+        serializer.serialize_f64(value.to_f64())
+    }
+
+    pub fn deserialize<'de, T, De, const D: u8>(
+        deserializer: De,
+    ) -> Result<FixedPoint<T, D>, De::Error>
+    where
+        T: FixedPointValue,
+        De: Deserializer<'de>,
+    {
+        deserializer.deserialize_f64(F64Visitor(PhantomData))
+    }
+
+    /// Converts a number to a `FixedPoint`, truncating it to the type's
+    /// decimals.
+    struct F64Visitor<T: FixedPointValue, const D: u8>(PhantomData<T>);
+
+    impl<'de, T: FixedPointValue, const D: u8> Visitor<'de> for F64Visitor<T, D> {
+        type Value = FixedPoint<T, D>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a number")
+        }
+
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+            FixedPoint::from_f64(v, RoundingMode::TowardZero).map_err(E::custom)
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+            from_int(v.is_negative(), v.unsigned_abs()).map_err(E::custom)
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+            from_int(false, v).map_err(E::custom)
+        }
+    }
+
+    /// Converts an integer to a `FixedPoint` exactly.
+    fn from_int<T: FixedPointValue, const D: u8>(
+        is_negative: bool,
+        abs: u64,
+    ) -> crate::Result<FixedPoint<T, D>> {
+        let abs = T::Unsigned::from_u128(abs.into())?;
+        FixedPoint::<T, 0>::from_sign_and_abs(FixedPointSign::from(!is_negative), abs)?
+            .rescale(RoundingMode::TowardZero)
+    }
+}
+
+// Helpers //
+
+/// Deserializes a string with the given parser.
+struct StrVisitor<F>(F, &'static str);
+
+impl<'de, F, V> Visitor<'de> for StrVisitor<F>
+where
+    F: FnOnce(&str) -> crate::Result<V>,
+{
+    type Value = V;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.1)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<V, E> {
+        (self.0)(v).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::{format, string::ToString};
+
+    use ethers::types::{I256, U256};
+    use rand::{thread_rng, Rng};
+    use serde::{Deserialize, Serialize};
+
+    use super::*;
+    use crate::{fixed, fixed_i256, fixed_u256};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Formats {
+        scaled: FixedPoint<I256>,
+        #[serde(with = "raw_dec")]
+        dec: FixedPoint<I256>,
+        #[serde(with = "raw_hex")]
+        hex: FixedPoint<I256>,
+        #[serde(with = "scaled_f64_lossy")]
+        float: FixedPoint<I256>,
+    }
+
+    #[test]
+    fn test_formats() -> eyre::Result<()> {
+        let value = fixed_i256!(-1.5e18);
+        let formats = Formats {
+            scaled: value,
+            dec: value,
+            hex: value,
+            float: value,
+        };
+        let json = serde_json::to_string(&formats)?;
+        assert_eq!(
+            json,
+            r#"{"scaled":"-1.500000000000000000","dec":"-1500000000000000000","hex":"-0x14d1120d7b160000","float":-1.5}"#
+        );
+        assert_eq!(serde_json::from_str::<Formats>(&json)?, formats);
+
+        // Scaled strings are parsed with `FromStr`.
+        let parse = |s: &str| serde_json::from_str::<FixedPoint<U256>>(&format!("{s:?}"));
+        assert_eq!(parse("1.5")?, fixed!(1.5e18));
+        assert_eq!(parse("2_000")?, fixed!(2_000e18));
+        assert_eq!(parse("1.25e3")?, fixed!(1_250e18));
+        assert_eq!(parse("15e-1")?, fixed!(1.5e18));
+        assert!(parse("1.0000000000000000001").is_err());
+        assert!(parse("-1").is_err());
+        assert_eq!(parse("0x10")?, fixed!(16e18));
+        assert!(parse("1e").is_err());
+
+        // Values outside of the type's range are rejected.
+        let max = serde_json::to_string(&FixedPoint::<U256>::MAX)?;
+        assert_eq!(
+            serde_json::from_str::<FixedPoint<U256>>(&max)?,
+            FixedPoint::MAX
+        );
+        assert!(serde_json::from_str::<FixedPoint<u128>>(&max).is_err());
+        let hex = format!(r#""0x1{}""#, "0".repeat(32));
+        assert!(
+            raw_hex::deserialize::<u128, _, 18>(&mut serde_json::Deserializer::from_str(&hex))
+                .is_err()
+        );
+
+        // Floats are converted exactly, e.g., `0.1` is
+        // `0.1000000000000000055511151231257827`, and truncated to the type's
+        // decimals.
+        let float =
+            serde_json::from_str::<Formats>(r#"{"scaled":"0","dec":"0","hex":"0x0","float":0.1}"#)?;
+        assert_eq!(float.float, fixed!(0.100000000000000005e18));
+        assert_eq!(float.hex, fixed!(0));
+
+        // Integers are converted exactly.
+        let float = |json: &str| {
+            scaled_f64_lossy::deserialize::<I256, _, 6>(&mut serde_json::Deserializer::from_str(
+                json,
+            ))
+        };
+        assert_eq!(float("-2")?, FixedPoint::new(-2_000_000));
+        assert_eq!(
+            float("9007199254740993")?.to_string(),
+            "9007199254740993.000000"
+        );
+        assert_eq!(float("-0.0000005")?, FixedPoint::new(0));
+        assert!(float("1e300").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn fuzz_round_trips() -> eyre::Result<()> {
+        let mut rng = thread_rng();
+        for _ in 0..10_000 {
+            let int: FixedPoint<I256> = rng.gen();
+            let json = serde_json::to_string(&int)?;
+            assert_eq!(serde_json::from_str::<FixedPoint<I256>>(&json)?, int);
+            assert_eq!(FixedPoint::from_dec_str(&format!("{int:#x}"))?, int);
+
+            let uint: FixedPoint<U256, 6> = rng.gen();
+            let json = serde_json::to_string(&uint)?;
+            assert_eq!(serde_json::from_str::<FixedPoint<U256, 6>>(&json)?, uint);
+            assert_eq!(
+                format!("{uint:#x}"),
+                format!("{:#x}", uint.raw()),
+                "hex of {uint}"
+            );
+        }
+        assert_eq!(format!("{:#x}", fixed_u256!(0)), "0x0");
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
-use std::fmt;
+use core::fmt;
 
+#[cfg(feature = "ethers")]
 use ethers::types::Sign;
 
 /// The sign associated with a fixed point number.
@@ -74,6 +75,7 @@ impl From<i8> for FixedPointSign {
     }
 }
 
+#[cfg(feature = "ethers")]
 impl From<Sign> for FixedPointSign {
     fn from(sign: Sign) -> FixedPointSign {
         match sign {
@@ -92,6 +94,7 @@ impl From<FixedPointSign> for bool {
     }
 }
 
+#[cfg(feature = "ethers")]
 impl From<FixedPointSign> for Sign {
     fn from(sign: FixedPointSign) -> Sign {
         match sign {
@@ -0,0 +1,490 @@
+//! Parsing and display of amounts written with a unit, e.g., `"30 gwei"`,
+//! `"50 bps"`, `"4.5%"`, or `"1.5 USDC"`.
+//!
+//! Amounts are parsed into values with the decimals of the `FixedPoint` type.
+//! Amounts in Ethereum denominations are parsed into values in ether, e.g.,
+//! `"30 gwei"` is `0.00000003`, amounts in token symbols into values in whole
+//! tokens, and basis points and percent into fractions, e.g., `"50 bps"` is
+//! `0.005`. Amounts can't be fractions of a wei or of the smallest unit of a
+//! token, i.e., have more decimal places than the decimals registered for it.
+//!
+//! # Example
+//!
+//! ```
+//! use ethers::types::U256;
+//! use fixedpointmath::{
+//!     fixed_u256,
+//!     units::{Unit, Units},
+//!     FixedPoint,
+//! };
+//!
+//! let mut units = Units::new();
+//! units.register("USDC", 6);
+//!
+//! let max_fee: FixedPoint<U256> = units.parse("30 gwei").unwrap();
+//! assert_eq!(max_fee.raw(), U256::from(30_000_000_000_u64));
+//! assert_eq!(max_fee.display_in(&Unit::Gwei).to_string(), "30 gwei");
+//!
+//! let slippage: FixedPoint<U256> = units.parse("50 bps").unwrap();
+//! assert_eq!(slippage, fixed_u256!(0.005e18));
+//! assert_eq!(slippage.display_in(&Unit::Percent).to_string(), "0.5%");
+//!
+//! let amount: FixedPoint<U256, 6> = units.parse("1.5 USDC").unwrap();
+//! assert_eq!(amount.raw(), U256::from(1_500_000));
+//! ```
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::{cmp::Reverse, fmt};
+
+use crate::{
+    parse::parse,
+    wide::{Extended, WideValue},
+    FixedPoint, FixedPointError, FixedPointValue, Result, RoundingMode,
+};
+
+/// A unit that amounts can be written in.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Unit {
+    /// `1e-18` ether.
+    Wei,
+    /// `1e-15` ether, also called babbage.
+    Kwei,
+    /// `1e-12` ether, also called lovelace.
+    Mwei,
+    /// `1e-9` ether, also called shannon.
+    Gwei,
+    /// `1e-6` ether, also called microether.
+    Szabo,
+    /// `1e-3` ether, also called milliether.
+    Finney,
+    /// Ether, also written as `eth`.
+    Ether,
+    /// Basis points, i.e., `1e-4`, also written as `bp`.
+    Bps,
+    /// Percent, i.e., `1e-2`, written as `%`.
+    Percent,
+    /// A token with a symbol and number of decimals, e.g., `USDC` with `6`.
+    Token { symbol: String, decimals: u8 },
+}
+
+impl Unit {
+    /// The built-in units, which are always recognized by [`Units`].
+    pub const BUILT_IN: [Unit; 9] = [
+        Unit::Wei,
+        Unit::Kwei,
+        Unit::Mwei,
+        Unit::Gwei,
+        Unit::Szabo,
+        Unit::Finney,
+        Unit::Ether,
+        Unit::Bps,
+        Unit::Percent,
+    ];
+
+    /// The symbol the unit is displayed with, e.g., `gwei` or `%`.
+    pub fn symbol(&self) -> &str {
+        self.names()[0]
+    }
+
+    /// The power of 10 that one of the unit is worth, where Ethereum
+    /// denominations are relative to ether, e.g., `-9` for gwei.
+    pub fn exponent(&self) -> i64 {
+        match self {
+            Unit::Wei => -18,
+            Unit::Kwei => -15,
+            Unit::Mwei => -12,
+            Unit::Gwei => -9,
+            Unit::Szabo => -6,
+            Unit::Finney => -3,
+            Unit::Ether | Unit::Token { .. } => 0,
+            Unit::Bps => -4,
+            Unit::Percent => -2,
+        }
+    }
+
+    /// The number of decimal places of the smallest amount in the unit, e.g.,
+    /// `18` for Ethereum denominations since amounts can't be fractions of a
+    /// wei, or `None` if amounts are only limited by the decimals of the
+    /// `FixedPoint` type.
+    pub fn decimals(&self) -> Option<u8> {
+        match self {
+            Unit::Bps | Unit::Percent => None,
+            Unit::Token { decimals, .. } => Some(*decimals),
+            _ => Some(18),
+        }
+    }
+
+    /// The names the unit is recognized by, starting with its symbol.
+    fn names(&self) -> &[&str] {
+        match self {
+            Unit::Wei => &["wei"],
+            Unit::Kwei => &["kwei", "babbage"],
+            Unit::Mwei => &["mwei", "lovelace"],
+            Unit::Gwei => &["gwei", "shannon"],
+            Unit::Szabo => &["szabo", "microether"],
+            Unit::Finney => &["finney", "milliether"],
+            Unit::Ether => &["ether", "eth"],
+            Unit::Bps => &["bps", "bp"],
+            Unit::Percent => &["%"],
+            Unit::Token { .. } => &[],
+        }
+    }
+}
+
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Token { symbol, .. } => f.write_str(symbol),
+            unit => f.write_str(unit.symbol()),
+        }
+    }
+}
+
+/// A table of the units amounts can be parsed with, which includes the
+/// [built-in units](Unit::BUILT_IN) and any registered tokens.
+///
+/// Units are matched ignoring ASCII case, and the built-in units take
+/// precedence over tokens with the same symbol.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Units {
+    /// The registered tokens by their lowercase symbol.
+    tokens: BTreeMap<String, Unit>,
+}
+
+impl Units {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a token symbol with the number of decimals its amounts are
+    /// scaled by, replacing any previous registration of the symbol.
+    pub fn register(&mut self, symbol: impl Into<String>, decimals: u8) -> &mut Self {
+        let symbol = symbol.into();
+        self.tokens.insert(
+            symbol.to_ascii_lowercase(),
+            Unit::Token { symbol, decimals },
+        );
+        self
+    }
+
+    /// Looks up a unit by one of its names, e.g., `"gwei"`, `"bps"`, or a
+    /// registered token symbol.
+    pub fn unit(&self, name: &str) -> Option<Unit> {
+        Unit::BUILT_IN
+            .into_iter()
+            .find(|unit| unit.names().iter().any(|n| n.eq_ignore_ascii_case(name)))
+            .or_else(|| self.tokens.get(&name.to_ascii_lowercase()).cloned())
+    }
+
+    /// Parses an amount with an optional unit after it, e.g., `"30 gwei"`,
+    /// `"4.5%"`, or `"1.5 USDC"`. The number accepts the same grammar as
+    /// [`FromStr`](FixedPoint#impl-FromStr-for-FixedPoint<T,+D>), and the
+    /// result is scaled by `D`.
+    ///
+    /// Amounts that aren't a whole number of the smallest amount in their unit
+    /// or of the result are rejected rather than rounded, e.g., `"0.5 wei"`,
+    /// `"1 wei"` with 6 decimals, or `"0.1234 bps"` with 6 decimals.
+    pub fn parse<T: FixedPointValue, const D: u8>(&self, s: &str) -> Result<FixedPoint<T, D>> {
+        let trimmed = s.trim();
+        let start = trimmed.as_ptr() as usize - s.as_ptr() as usize;
+        let (number, unit) = self
+            .split(trimmed)
+            .map_err(|(position, reason)| FixedPointError::parse(s, start + position, reason))?;
+
+        let parse = |decimals: u8| {
+            let exponent = i64::from(decimals) + unit.as_ref().map_or(0, Unit::exponent);
+            parse::<T>(number, exponent).map_err(|err| match err {
+                FixedPointError::Parse {
+                    position, reason, ..
+                } => FixedPointError::parse(s, start + position, reason),
+                err => err,
+            })
+        };
+        let raw = parse(D)?;
+
+        // An amount with at most `D` decimal places fits in `T` with fewer
+        // decimals, so only a unit with fewer decimals needs to be checked.
+        if let Some(decimals) = unit.as_ref().and_then(Unit::decimals).filter(|&d| d < D) {
+            parse(decimals)?;
+        }
+        Ok(FixedPoint::new(raw))
+    }
+
+    /// Splits a trimmed amount into its number and unit, returning the
+    /// position and reason of the error if the unit is unknown.
+    fn split<'a>(&self, s: &'a str) -> Result<(&'a str, Option<Unit>), (usize, String)> {
+        // Longest first so that, e.g., `gwei` isn't matched as `wei`.
+        let mut names = Unit::BUILT_IN
+            .iter()
+            .flat_map(Unit::names)
+            .copied()
+            .chain(self.tokens.keys().map(String::as_str))
+            .collect::<Vec<_>>();
+        names.sort_by_key(|name| Reverse(name.len()));
+
+        for name in names {
+            let start = s.len().saturating_sub(name.len());
+            if start > 0 && s.is_char_boundary(start) && s[start..].eq_ignore_ascii_case(name) {
+                return Ok((s[..start].trim_end(), self.unit(name)));
+            }
+        }
+
+        // A word after whitespace is an unknown unit rather than part of the
+        // number.
+        match s.rfind(char::is_whitespace) {
+            Some(end) => {
+                let name = s[end..].trim_start();
+                Err((s.len() - name.len(), format!("unknown unit {name:?}")))
+            }
+            None => Ok((s, None)),
+        }
+    }
+}
+
+impl<T: FixedPointValue, const D: u8> FixedPoint<T, D> {
+    /// Returns an adapter that displays the value in a unit, e.g., `30 gwei`,
+    /// interpreting values in Ethereum denominations as amounts of ether.
+    ///
+    /// Trailing zeros are trimmed unless a precision is given, in which case
+    /// the value is rounded half to even, e.g., `{:.2}` displays `0.125%` as
+    /// `0.12%`. The width, fill, and `+` flags apply to the whole output.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use fixedpointmath::{fixed_u256, units::Unit};
+    ///
+    /// let rate = fixed_u256!(0.045e18);
+    /// assert_eq!(rate.display_in(&Unit::Percent).to_string(), "4.5%");
+    /// assert_eq!(rate.display_in(&Unit::Bps).to_string(), "450 bps");
+    /// assert_eq!(format!("{:.3}", rate.display_in(&Unit::Ether)), "0.045 ether");
+    /// ```
+    pub fn display_in<'a>(&'a self, unit: &'a Unit) -> UnitDisplay<'a, T, D> {
+        UnitDisplay { value: self, unit }
+    }
+}
+
+/// Displays a `FixedPoint` in a unit, see [`FixedPoint::display_in`].
+#[derive(Clone, Copy, Debug)]
+pub struct UnitDisplay<'a, T: FixedPointValue, const D: u8> {
+    value: &'a FixedPoint<T, D>,
+    unit: &'a Unit,
+}
+
+impl<T: FixedPointValue, const D: u8> fmt::Display for UnitDisplay<'_, T, D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // The number of decimal places of the raw value in the unit.
+        let places = i64::from(self.value.decimals()) + self.unit.exponent();
+        let mut abs = Extended::<T>::from(self.value.raw().unsigned_abs());
+        let mut places = match usize::try_from(places) {
+            Ok(places) => places,
+            Err(_) => {
+                abs = abs * Extended::<T>::exp10(places.unsigned_abs() as usize);
+                0
+            }
+        };
+        if let Some(precision) = f.precision().filter(|&precision| precision < places) {
+            let scale = Extended::<T>::exp10(places - precision);
+            let (quotient, remainder) = abs.div_mod(scale.clone());
+            abs = RoundingMode::HalfEven.round(self.value.sign(), quotient, remainder, scale);
+            places = precision;
+        }
+
+        // Pad the digits so there's at least one integer digit.
+        let digits = format!("{:0>width$}", abs.to_string(), width = places + 1);
+        let (int, fraction) = digits.split_at(digits.len() - places);
+        let number = match f.precision() {
+            Some(0) => int.to_string(),
+            Some(precision) => format!("{int}.{fraction:0<precision$}"),
+            None => match fraction.trim_end_matches('0') {
+                "" => int.to_string(),
+                fraction => format!("{int}.{fraction}"),
+            },
+        };
+        let separator = match self.unit {
+            Unit::Percent => "",
+            _ => " ",
+        };
+        f.pad_integral(
+            !self.value.is_negative(),
+            "",
+            &format!("{number}{separator}{}", self.unit),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ethers::types::{I256, U256};
+
+    use super::*;
+    use crate::{fixed, fixed_i256, fixed_u256, uint256};
+
+    #[test]
+    fn test_parse() -> Result<()> {
+        let mut units = Units::new();
+        units.register("USDC", 6).register("wBTC", 8);
+
+        // Ethereum denominations are parsed as ether with 18 decimals.
+        let parse = |s: &str| units.parse::<U256, 18>(s);
+        assert_eq!(parse("30 gwei")?.raw(), uint256!(30e9));
+        assert_eq!(parse("30gwei")?.raw(), uint256!(30e9));
+        assert_eq!(parse("1.5 Shannon")?.raw(), uint256!(1.5e9));
+        assert_eq!(parse("  1_000 wei ")?.raw(), uint256!(1_000));
+        assert_eq!(parse("0.01 ETH")?, fixed!(0.01e18));
+        assert_eq!(parse("2 finney")?, fixed!(0.002e18));
+        assert_eq!(
+            units.parse::<U256, 6>("1 ether")?,
+            FixedPoint::new(1_000_000_u128)
+        );
+        assert_eq!(units.parse::<u32, 9>("2 gwei")?, FixedPoint::new(2_u32));
+
+        // Basis points, percent, and bare numbers use the type's decimals.
+        assert_eq!(parse("50 bps")?, fixed!(0.005e18));
+        assert_eq!(parse("1 bp")?, fixed!(0.0001e18));
+        assert_eq!(parse("4.5%")?, fixed!(0.045e18));
+        assert_eq!(parse("4.5 %")?, fixed!(0.045e18));
+        assert_eq!(parse("1.25")?, fixed!(1.25e18));
+        assert_eq!(parse("1e3")?, fixed!(1_000e18));
+        assert_eq!(units.parse::<I256, 18>("-2.5%")?, fixed_i256!(-0.025e18));
+
+        // Tokens are parsed in whole tokens.
+        assert_eq!(parse("1.5 USDC")?, fixed!(1.5e18));
+        assert_eq!(parse("0.1 wbtc")?, fixed!(0.1e18));
+        assert_eq!(
+            units.parse::<U256, 6>("1.5 USDC")?,
+            FixedPoint::new(1_500_000_u128)
+        );
+        assert_eq!(
+            units.unit("usdc"),
+            Some(Unit::Token {
+                symbol: "USDC".into(),
+                decimals: 6
+            })
+        );
+        assert_eq!(units.unit("Gwei"), Some(Unit::Gwei));
+        assert_eq!(units.unit("dai"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let mut units = Units::new();
+        units.register("USDC", 6);
+        let parse = |s: &str| units.parse::<U256, 18>(s);
+
+        assert_eq!(
+            parse("1 dai"),
+            Err(FixedPointError::parse("1 dai", 2, "unknown unit \"dai\""))
+        );
+        assert_eq!(
+            parse("0.5 wei"),
+            Err(FixedPointError::parse(
+                "0.5 wei",
+                2,
+                "more than 0 decimal places"
+            ))
+        );
+        assert_eq!(
+            parse(" 1.0000001 USDC"),
+            Err(FixedPointError::parse(
+                " 1.0000001 USDC",
+                9,
+                "more than 6 decimal places"
+            ))
+        );
+        assert_eq!(
+            units.parse::<U256, 2>("0.5 bps"),
+            Err(FixedPointError::parse(
+                "0.5 bps",
+                2,
+                "not a multiple of 1e2"
+            ))
+        );
+        assert_eq!(
+            parse("gwei"),
+            Err(FixedPointError::parse(
+                "gwei",
+                0,
+                "expected digits, found 'g'"
+            ))
+        );
+        assert_eq!(
+            parse("1x gwei"),
+            Err(FixedPointError::parse(
+                "1x gwei",
+                1,
+                "unexpected character 'x'"
+            ))
+        );
+        assert_eq!(
+            units.parse::<U256, 6>("1 wei"),
+            Err(FixedPointError::parse("1 wei", 0, "not a multiple of 1e12"))
+        );
+        assert_eq!(
+            units.parse::<u32, 9>("1.5 wei"),
+            Err(FixedPointError::parse(
+                "1.5 wei",
+                0,
+                "not a multiple of 1e9"
+            ))
+        );
+        assert!(matches!(
+            parse("-1 gwei"),
+            Err(FixedPointError::NegativeToUnsigned { .. })
+        ));
+    }
+
+    #[test]
+    fn test_display_in() -> Result<()> {
+        let fee = fixed_u256!(30e9);
+        assert_eq!(fee.display_in(&Unit::Gwei).to_string(), "30 gwei");
+        assert_eq!(fee.display_in(&Unit::Wei).to_string(), "30000000000 wei");
+        assert_eq!(fee.display_in(&Unit::Ether).to_string(), "0.00000003 ether");
+        assert_eq!(format!("{:.1}", fee.display_in(&Unit::Gwei)), "30.0 gwei");
+        assert_eq!(format!("{:>10}", fee.display_in(&Unit::Gwei)), "   30 gwei");
+
+        // Values with fewer decimals than the unit are scaled up.
+        let fee = FixedPoint::<U256, 6>::new(3_u128);
+        assert_eq!(fee.display_in(&Unit::Wei).to_string(), "3000000000000 wei");
+
+        let rate = fixed_i256!(-0.00125e18);
+        assert_eq!(rate.display_in(&Unit::Percent).to_string(), "-0.125%");
+        assert_eq!(format!("{:.2}", rate.display_in(&Unit::Percent)), "-0.12%");
+        assert_eq!(format!("{:.0}", rate.display_in(&Unit::Bps)), "-12 bps");
+        assert_eq!(
+            format!("{:+}", fixed_u256!(0.5e18).display_in(&Unit::Bps)),
+            "+5000 bps"
+        );
+
+        let usdc = Unit::Token {
+            symbol: "USDC".into(),
+            decimals: 6,
+        };
+        let amount = FixedPoint::<U256, 6>::new(1_500_000_u128);
+        assert_eq!(amount.display_in(&usdc).to_string(), "1.5 USDC");
+
+        // Displayed values parse back to the same value.
+        let mut units = Units::new();
+        units.register("USDC", 6);
+        let s = amount.display_in(&usdc).to_string();
+        assert_eq!(units.parse::<U256, 6>(&s)?, amount);
+        for (value, unit) in [
+            (fixed_u256!(1.234e18), Unit::Gwei),
+            (fixed_u256!(0.005e18), Unit::Bps),
+            (fixed_u256!(123), Unit::Wei),
+            (fixed_u256!(1.5e18), usdc),
+        ] {
+            let s = value.display_in(&unit).to_string();
+            assert_eq!(units.parse::<U256, 18>(&s)?, value, "{s}");
+        }
+
+        Ok(())
+    }
+}
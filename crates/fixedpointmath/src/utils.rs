@@ -1,12 +1,15 @@
-use std::ops::Shr;
+use alloc::string::ToString;
+use core::ops::Shr;
 
-use ethers::types::{I256, U256};
-use eyre::{bail, Result};
+#[cfg(feature = "ethers")]
+use ethers::types::I256;
+use primitive_types::{U256, U512};
 
-use crate::{int256, uint256};
+use crate::{i256::Int256, parse::parse, uint256, FixedPointError, FixedPointValue, Result};
 
 /// Parses a string into a U256 with support for scientific and decimal
-/// notation.
+/// notation, `_` separators, and `0x`, `0b`, and `0o` prefixes. The value must
+/// be an integer after applying its exponent.
 ///
 /// ## Example
 ///
@@ -16,49 +19,30 @@ use crate::{int256, uint256};
 ///
 /// let u = u256_from_str("1.1e18").unwrap();
 /// assert_eq!(u, U256::from(11) * U256::from(10).pow(U256::from(17)));
+/// assert_eq!(u256_from_str("0xff").unwrap(), U256::from(255));
+/// assert!(u256_from_str("1e").is_err());
 /// ```
 pub fn u256_from_str(s: &str) -> Result<U256> {
-    // Parse a string into a mantissa and an exponent. The U256 arithmetic will
-    // overflow if the mantissa or the exponent are too large.
-    let mut found_dot = false;
-    let mut found_e = false;
-    let mut mantissa = ethers::types::U256::zero();
-    let mut exponent = ethers::types::U256::zero();
-    let mut decimals = 0;
-
-    for digit in s.chars() {
-        if digit.is_ascii_digit() {
-            let d = digit.to_digit(10).unwrap();
-            if !found_e {
-                mantissa = mantissa * 10 + d;
-            } else {
-                exponent = exponent * 10 + d;
-            }
-            if found_dot && !found_e {
-                decimals += 1;
-            }
-        } else if digit == 'e' && !found_e {
-            found_e = true;
-        } else if digit == '.' && !found_dot {
-            found_dot = true;
-        } else if digit != '_' {
-            bail!("Unexpected character in U256: {digit}");
-        }
-    }
-
-    // Combine the mantissa and the exponent into a single U256. This will
-    // overflow if the exponent is too large. We also need to make sure that the
-    // final result is an integer.
-    let decimals = ethers::types::U256::from(decimals);
-    if exponent < decimals {
-        bail!("Exponent {exponent} is too small for U256: {s}");
-    }
+    parse(s, 0)
+}
 
-    Ok(mantissa * ethers::types::U256::from(10).pow(exponent - decimals))
+/// Parses a string into a U512 with support for scientific and decimal
+/// notation, `_` separators, and `0x`, `0b`, and `0o` prefixes.
+///
+/// ## Example
+///
+/// ```
+/// use fixedpointmath::{u512_from_str, U512};
+///
+/// let u = u512_from_str("1e100").unwrap();
+/// assert_eq!(u, U512::from(10).pow(U512::from(100)));
+/// ```
+pub fn u512_from_str(s: &str) -> Result<U512> {
+    parse(s, 0)
 }
 
-/// Parse a string into an I256 with support for scientific and decimal
-/// notation.
+/// Parse a string into an I256 with support for a leading sign, scientific and
+/// decimal notation, `_` separators, and `0x`, `0b`, and `0o` prefixes.
 ///
 /// ## Example
 ///
@@ -68,74 +52,46 @@ pub fn u256_from_str(s: &str) -> Result<U256> {
 ///
 /// let i = i256_from_str("-1.1e18").unwrap();
 /// assert_eq!(i, -I256::from(11) * I256::from(10).pow(17));
+/// assert!(i256_from_str("1-2").is_err());
 /// ```
+#[cfg(feature = "ethers")]
 pub fn i256_from_str(s: &str) -> Result<I256> {
-    // Parse a string into a mantissa and an exponent. The U256 arithmetic will
-    // overflow if the mantissa or the exponent are too large.
-    let mut sign = ethers::types::I256::one();
-    let mut found_dot = false;
-    let mut found_e = false;
-    let mut mantissa = ethers::types::I256::zero();
-    let mut exponent = 0;
-    let mut decimals = 0;
-
-    for digit in s.chars() {
-        if digit.is_ascii_digit() {
-            let d = digit.to_digit(10).unwrap();
-            if !found_e {
-                mantissa = mantissa * 10 + d;
-            } else {
-                exponent = exponent * 10 + d;
-            }
-            if found_dot && !found_e {
-                decimals += 1;
-            }
-        } else if digit == '-' {
-            sign = -ethers::types::I256::one();
-        } else if digit == 'e' && !found_e {
-            found_e = true;
-        } else if digit == '.' && !found_dot {
-            found_dot = true;
-        } else if digit != '_' {
-            bail!("Unexpected character in I256: {digit}");
-        }
-    }
+    parse(s, 0)
+}
 
-    // Combine the mantissa and the exponent I256. This will overflow if the
-    // exponent is too large. We also need to make sure that the final result is
-    // an integer.
-    if exponent < decimals {
-        bail!("Exponent {exponent} is too small for I256: {s}");
-    }
+// Math //
 
-    Ok(sign * mantissa * ethers::types::I256::from(10).pow(exponent - decimals))
+/// Computes `e^x` where `x` and the result are scaled by `1e18`, as in
+/// FixedPointMath.sol.
+pub fn exp<T: FixedPointValue>(x: T) -> Result<T> {
+    exp_int256(Int256::try_from_value(x)?)?.try_into_value()
 }
 
-/// Math
-
-pub fn exp(mut x: I256) -> Result<I256> {
+pub(crate) fn exp_int256(mut x: Int256) -> Result<Int256> {
     // When the result is < 0.5 we return zero. This happens when x <=
     // floor(log(0.5e18) * 1e18) ~ -42e18
-    if x <= I256::from(-42139678854452767551_i128) {
-        return Ok(I256::zero());
+    if x <= Int256::from_i128(-42139678854452767551) {
+        return Ok(Int256::ZERO);
     }
 
     // When the result is > (2**255 - 1) / 1e18 we can not represent it as an
     // int. This happens when x >= floor(log((2**255 - 1) / 1e18) * 1e18) ~ 135.
-    if x >= int256!(135305999368893231589) {
-        bail!("invalid exponent {x}");
+    if x >= Int256::from(135305999368893231589_u128) {
+        return Err(FixedPointError::ExpOverflow {
+            value: x.to_string(),
+        });
     }
 
     // x is now in the range (-42, 136) * 1e18. Convert to (-42, 136) * 2**96
     // for more intermediate precision and a binary basis. This base conversion
     // is a multiplication by 1e18 / 2**96 = 5**18 / 2**78.
-    x = x.wrapping_shl(78) / int256!(5).pow(18);
+    x = x.wrapping_shl(78) / Int256::from(5_u128.pow(18));
 
     // Reduce range of x to (-½ ln 2, ½ ln 2) * 2**96 by factoring out powers of
     // two such that exp(x) = exp(x') * 2**k, where k is an integer. Solving
     // this gives k = round(x / log(2)) and x' = x - k * log(2).
-    let k = ((x.wrapping_shl(96) / int256!(54916777467707473351141471128))
-        .wrapping_add(int256!(2).pow(95)))
+    let k = ((x.wrapping_shl(96) / Int256::from(54916777467707473351141471128_u128))
+        .wrapping_add(Int256::from(2_u128.pow(95))))
     .asr(96);
     x = x.wrapping_sub(k.wrapping_mul(54916777467707473351141471128_u128.into()));
 
@@ -157,7 +113,7 @@ pub fn exp(mut x: I256) -> Result<I256> {
         .wrapping_add(28719021644029726153956944680412240_u128.into());
     p = p
         .wrapping_mul(x)
-        .wrapping_add(int256!(4385272521454847904659076985693276).wrapping_shl(96));
+        .wrapping_add(Int256::from(4385272521454847904659076985693276_u128).wrapping_shl(96));
 
     // We leave p in 2**192 basis so we don't need to scale it back up for the
     // division.
@@ -193,19 +149,27 @@ pub fn exp(mut x: I256) -> Result<I256> {
     // * the 1e18 / 2**96 factor for base conversion. We do this all at once,
     // with an intermediate result in 2**213 basis, so the final right shift is
     // always by a positive amount.
-    r = I256::from_raw(
+    r = Int256::from_raw(
         (r.into_raw()
             .overflowing_mul(uint256!(3822833074963236453042738258902158003155416615667))
             .0)
-            .shr(int256!(195).wrapping_sub(k).low_usize()),
+            .shr(Int256::from(195_u128).wrapping_sub(k).low_usize()),
     );
 
     Ok(r)
 }
 
-pub fn ln(mut x: I256) -> Result<I256> {
-    if x <= I256::zero() {
-        bail!("Cannot calculate ln of of negative number or zero.");
+/// Computes the natural logarithm of `x` where `x` and the result are scaled
+/// by `1e18`, as in FixedPointMath.sol.
+pub fn ln<T: FixedPointValue>(x: T) -> Result<T> {
+    ln_int256(Int256::try_from_value(x)?)?.try_into_value()
+}
+
+pub(crate) fn ln_int256(mut x: Int256) -> Result<Int256> {
+    if x <= Int256::ZERO {
+        return Err(FixedPointError::LnNonPositive {
+            value: x.to_string(),
+        });
     }
 
     // We want to convert x from 10**18 fixed point to 2**96 fixed point. We do
@@ -213,58 +177,66 @@ pub fn ln(mut x: I256) -> Result<I256> {
     // ln(C), we can simply do nothing here and add ln(2**96 / 10**18) at the
     // end.
 
-    let mut r = I256::from((x > I256::from(0xffffffffffffffffffffffffffffffff_u128)) as u128)
+    let mut r = Int256::from((x > Int256::from(0xffffffffffffffffffffffffffffffff_u128)) as u128)
         .wrapping_shl(7);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0xffffffffffffffff_u128)) as u128)
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0xffffffffffffffff_u128)) as u128)
         .wrapping_shl(6);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0xffffffff_u128)) as u128).wrapping_shl(5);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0xffff_u128)) as u128).wrapping_shl(4);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0xff_u128)) as u128).wrapping_shl(3);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0xf_u128)) as u128).wrapping_shl(2);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0x3_u128)) as u128).wrapping_shl(1);
-    r = r | I256::from((x.asr(r.as_usize()) > I256::from(0x1_u128)) as u128);
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0xffffffff_u128)) as u128)
+        .wrapping_shl(5);
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0xffff_u128)) as u128)
+        .wrapping_shl(4);
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0xff_u128)) as u128).wrapping_shl(3);
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0xf_u128)) as u128).wrapping_shl(2);
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0x3_u128)) as u128).wrapping_shl(1);
+    r = r | Int256::from((x.asr(r.low_usize()) > Int256::from(0x1_u128)) as u128);
 
     // Reduce range of x to (1, 2) * 2**96 ln(2^k * x) = k * ln(2) + ln(x)
-    let k = r.wrapping_sub(int256!(96));
-    x = x.wrapping_shl(int256!(159).wrapping_sub(k).as_usize());
-    x = I256::from_raw(x.into_raw().shr(159));
+    let k = r.wrapping_sub(Int256::from(96_u128));
+    x = x.wrapping_shl(Int256::from(159_u128).wrapping_sub(k).low_usize());
+    x = Int256::from_raw(x.into_raw().shr(159));
 
     // Evaluate using a (8, 8)-term rational approximation. p is made monic, we
     // will multiply by a scale factor later.
-    let mut p = x.wrapping_add(int256!(3273285459638523848632254066296));
-    p = ((p.wrapping_mul(x)).asr(96)).wrapping_add(int256!(24828157081833163892658089445524));
-    p = ((p.wrapping_mul(x)).asr(96)).wrapping_add(int256!(43456485725739037958740375743393));
-    p = ((p.wrapping_mul(x)).asr(96)).wrapping_sub(int256!(11111509109440967052023855526967));
-    p = ((p.wrapping_mul(x)).asr(96)).wrapping_sub(int256!(45023709667254063763336534515857));
-    p = ((p.wrapping_mul(x)).asr(96)).wrapping_sub(int256!(14706773417378608786704636184526));
+    let mut p = x.wrapping_add(Int256::from(3273285459638523848632254066296_u128));
+    p = ((p.wrapping_mul(x)).asr(96))
+        .wrapping_add(Int256::from(24828157081833163892658089445524_u128));
+    p = ((p.wrapping_mul(x)).asr(96))
+        .wrapping_add(Int256::from(43456485725739037958740375743393_u128));
+    p = ((p.wrapping_mul(x)).asr(96))
+        .wrapping_sub(Int256::from(11111509109440967052023855526967_u128));
+    p = ((p.wrapping_mul(x)).asr(96))
+        .wrapping_sub(Int256::from(45023709667254063763336534515857_u128));
+    p = ((p.wrapping_mul(x)).asr(96))
+        .wrapping_sub(Int256::from(14706773417378608786704636184526_u128));
     p = p
         .wrapping_mul(x)
-        .wrapping_sub(int256!(795164235651350426258249787498).wrapping_shl(96));
+        .wrapping_sub(Int256::from(795164235651350426258249787498_u128).wrapping_shl(96));
 
     // We leave p in 2**192 basis so we don't need to scale it back up for the
     // division. q is monic by convention.
-    let mut q = x.wrapping_add(int256!(5573035233440673466300451813936));
-    q = (q.wrapping_mul(x).asr(96)).wrapping_add(int256!(71694874799317883764090561454958));
+    let mut q = x.wrapping_add(Int256::from(5573035233440673466300451813936_u128));
+    q = (q.wrapping_mul(x).asr(96))
+        .wrapping_add(Int256::from(71694874799317883764090561454958_u128));
     q = q
         .wrapping_mul(x)
         .asr(96)
-        .wrapping_add(int256!(283447036172924575727196451306956));
+        .wrapping_add(Int256::from(283447036172924575727196451306956_u128));
     q = q
         .wrapping_mul(x)
         .asr(96)
-        .wrapping_add(int256!(401686690394027663651624208769553));
+        .wrapping_add(Int256::from(401686690394027663651624208769553_u128));
     q = q
         .wrapping_mul(x)
         .asr(96)
-        .wrapping_add(int256!(204048457590392012362485061816622));
+        .wrapping_add(Int256::from(204048457590392012362485061816622_u128));
     q = q
         .wrapping_mul(x)
         .asr(96)
-        .wrapping_add(int256!(31853899698501571402653359427138));
+        .wrapping_add(Int256::from(31853899698501571402653359427138_u128));
     q = q
         .wrapping_mul(x)
         .asr(96)
-        .wrapping_add(int256!(909429971244387300277376558375));
+        .wrapping_add(Int256::from(909429971244387300277376558375_u128));
 
     r = p.wrapping_div(q);
 
@@ -277,31 +249,79 @@ pub fn ln(mut x: I256) -> Result<I256> {
     // * multiply by 10**18 / 2**96 = 5**18 >> 78
 
     // mul s * 5e18 * 2**96, base is now 5**18 * 2**192
-    r = r.wrapping_mul(int256!(1677202110996718588342820967067443963516166));
+    r = r.wrapping_mul(Int256::from_raw(uint256!(
+        1677202110996718588342820967067443963516166
+    )));
     // add ln(2) * k * 5e18 * 2**192
     r = r.wrapping_add(
-        int256!(16597577552685614221487285958193947469193820559219878177908093499208371)
-            .wrapping_mul(k),
+        Int256::from_raw(uint256!(
+            16597577552685614221487285958193947469193820559219878177908093499208371
+        ))
+        .wrapping_mul(k),
     );
     // add ln(2**96 / 10**18) * 5e18 * 2**192
-    r = r.wrapping_add(int256!(
+    r = r.wrapping_add(Int256::from_raw(uint256!(
         600920179829731861736702779321621459595472258049074101567377883020018308
-    ));
+    )));
     // base conversion: mul 2**18 / 2**192
     r = r.asr(174);
 
     Ok(r)
 }
 
+/// Computes `x^y` for a non-negative `y` where `x`, `y`, and the result are
+/// scaled by `1e18`, as in FixedPointMath.sol.
+pub(crate) fn pow_int256(x: Int256, y: Int256) -> Result<Int256> {
+    let one = Int256::from(10_u128.pow(18));
+
+    // If the exponent is 0, return 1.
+    if y == Int256::ZERO {
+        return Ok(one);
+    }
+
+    // If the base is 0, return 0.
+    if x == Int256::ZERO {
+        return Ok(Int256::ZERO);
+    }
+
+    // Using properties of logarithms we calculate x^y: -> ln(x^y) = y * ln(x)
+    // -> e^(y * ln(x)) = x^y
+
+    // Compute y*ln(x) Any overflow for x will be caught in _ln() in the initial
+    // bounds check
+    let lnx = ln_int256(x)?;
+    let ylnx = y.wrapping_mul(lnx).wrapping_div(one);
+
+    // Calculate exp(y * ln(x)) to get x^y
+    exp_int256(ylnx)
+}
+
 #[cfg(test)]
 mod tests {
     use ethers::signers::Signer;
+    use eyre::Result;
     use hyperdrive_wrappers::wrappers::mock_fixed_point_math::MockFixedPointMath;
     use rand::{thread_rng, Rng};
     use test_utils::{chain::Chain, constants::DEPLOYER};
 
     use super::*;
-    use crate::{fixed, uint256, FixedPoint};
+    use crate::{fixed, int256, uint256, FixedPoint};
+
+    #[test]
+    fn test_exp_ln_errors() {
+        assert_eq!(
+            exp(int256!(136e18)),
+            Err(FixedPointError::ExpOverflow {
+                value: int256!(136e18).to_string()
+            })
+        );
+        assert_eq!(
+            ln(I256::zero()),
+            Err(FixedPointError::LnNonPositive {
+                value: "0".to_string()
+            })
+        );
+    }
 
     #[tokio::test]
     async fn fuzz_exp_narrow() -> Result<()> {
@@ -1,11 +1,13 @@
-use std::{
+use alloc::{format, string::ToString, vec::Vec};
+use core::{
     fmt::Debug,
     ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, Sub, SubAssign},
 };
 
-use ethers::types::U256;
-use eyre::{eyre, Result};
 use paste::paste;
+use primitive_types::{U256, U512};
+
+use crate::{wide::WideValue, FixedPointError, Result};
 
 /// Adds `from_<type>` and `to_<type>` conversion functions for a list of types.
 macro_rules! conversion_fns {
@@ -13,25 +15,27 @@ macro_rules! conversion_fns {
         $(
         paste! {
             fn [<from_ $type_name:snake>](value: $type_name) -> Result<Self> {
-                Self::try_from(value).map_err(|_| {
-                    eyre!(
-                        r#"Failed to convert {type} to underlying FixedPointValue type:
-    {type} value: {value:?}
-    Underlying range: {min:?} to {max:?}
-"#,
-                        type = stringify!($type_name),
-                        min = Self::MIN,
-                        max = Self::MAX,
-                    )
+                Self::try_from(value).map_err(|_| FixedPointError::OutOfRange {
+                    value: format!("{value:?}"),
+                    target: core::any::type_name::<Self>(),
                 })
             }
 
             fn [<to_ $type_name:snake>](self) -> Result<$type_name> {
-                self.try_into().map_err(|_| {
-                    eyre!(
-                        "Failed to convert underlying FixedPointValue to {type}: {self:?}",
-                        type = stringify!($type_name)
-                   )
+                self.clone().try_into().map_err(|_| {
+                    // NOTE: The conversion functions are only generated for
+                    // unsigned types.
+                    if self.is_negative() {
+                        FixedPointError::NegativeToUnsigned {
+                            value: format!("{self:?}"),
+                            target: stringify!($type_name),
+                        }
+                    } else {
+                        FixedPointError::OutOfRange {
+                            value: format!("{self:?}"),
+                            target: stringify!($type_name),
+                        }
+                    }
                 })
             }
         })*
@@ -40,10 +44,18 @@ macro_rules! conversion_fns {
 
 /// A value that can be used to perform fixed-point math.
 ///
-/// All methods have default implementations based on comparisons to `0`, but
-/// can be overridden to provide more efficient alternatives.
+/// Values are converted to and from their [`FixedPointValue::Unsigned`] type
+/// with [`FixedPointValue::from_unsigned`] and
+/// [`FixedPointValue::to_unsigned`], which makes the math independent of the
+/// integer library backing `Self`. The native integers use the native unsigned
+/// integer of the same size, the other fixed-size integers use `U512` so the
+/// math supports values up to 512 bits, and arbitrary-precision integers use an
+/// arbitrary-precision unsigned type so the math is unbounded. The remaining
+/// methods have default implementations based on comparisons to
+/// `Self::default()`, which must be `0`, but can be overridden to provide more
+/// efficient alternatives.
 pub trait FixedPointValue:
-    Copy
+    Clone
     + Debug
     + Default
     + Sized
@@ -62,28 +74,88 @@ pub trait FixedPointValue:
     + Rem<Output = Self>
     + TryFrom<u128>
     + TryInto<u128>
-    + From<u64>
-    + TryFrom<U256>
-    + TryInto<U256>
 {
-    /// The minimum value that can be represented by the type.
-    /// Must be `-2^256-1..=2^256 - 1`.
-    const MIN: Self;
+    /// The unsigned type used for the absolute values of `Self`, e.g., `u64`
+    /// for `i64`.
+    type Unsigned: UnsignedValue;
+
+    /// The minimum value that can be represented by the type, or `None` if the
+    /// type is unbounded. Must be `-2^512..=0` for bounded types.
+    const MIN: Option<Self>;
 
-    /// The maximum value that can be represented by the type.
-    /// Must be `0..=2^256 - 1`.
-    const MAX: Self;
+    /// The maximum value that can be represented by the type, or `None` if the
+    /// type is unbounded. Must be `0..=2^512 - 1` for bounded types.
+    const MAX: Option<Self>;
 
-    /// The maximum number of decimal places the value can support.
+    /// The maximum number of decimal places the value can support. `10` to the
+    /// power of `MAX_DECIMALS` must fit in the type.
     const MAX_DECIMALS: u8 = 18;
 
-    /// Whether the value supports negation.
+    /// Converts an unsigned value to `Self`, returning an error if it's out of
+    /// range.
+    fn from_unsigned(value: Self::Unsigned) -> Result<Self>;
+
+    /// Converts self to its unsigned type, returning an error if it's negative
+    /// or out of range.
+    fn to_unsigned(self) -> Result<Self::Unsigned>;
+
+    /// Converts a `U512` to `Self`, returning an error if it's out of range.
+    fn from_u512(value: U512) -> Result<Self> {
+        let unsigned =
+            Self::Unsigned::from_limbs(&value.0).ok_or_else(|| FixedPointError::OutOfRange {
+                value: value.to_string(),
+                target: core::any::type_name::<Self>(),
+            })?;
+        Self::from_unsigned(unsigned)
+    }
+
+    /// Converts self to a `U512`, returning an error if it's negative or out
+    /// of range.
+    fn to_u512(self) -> Result<U512> {
+        let value = format!("{self:?}");
+        let limbs = self.to_unsigned()?.into_limbs();
+        let (low, high) = limbs.split_at(limbs.len().min(8));
+        if high.iter().any(|&limb| limb != 0) {
+            return Err(FixedPointError::OutOfRange {
+                value,
+                target: "U512",
+            });
+        }
+        let mut words = [0; 8];
+        words[..low.len()].copy_from_slice(low);
+        Ok(U512(words))
+    }
+
+    /// Converts a `U256` to `Self`, returning an error if it's out of range.
+    fn from_u256(value: U256) -> Result<Self> {
+        Self::from_u512(value.into())
+    }
+
+    /// Converts self to a `U256`, returning an error if it's negative or out
+    /// of range.
+    fn to_u256(self) -> Result<U256> {
+        if self.is_negative() {
+            return Err(FixedPointError::NegativeToUnsigned {
+                value: format!("{self:?}"),
+                target: "U256",
+            });
+        }
+        let out_of_range = || FixedPointError::OutOfRange {
+            value: format!("{self:?}"),
+            target: "U256",
+        };
+        let wide = self.clone().to_u512().map_err(|_| out_of_range())?;
+        U256::try_from(wide).map_err(|_| out_of_range())
+    }
+
+    /// Whether the value supports negation. Unbounded signed types must
+    /// override this since it's based on `Self::MIN`.
     fn is_signed() -> bool {
-        Self::MIN.is_negative()
+        Self::MIN.is_some_and(|min| min.is_negative())
     }
 
     fn is_negative(&self) -> bool {
-        self < &Self::from(0)
+        self < &Self::default()
     }
 
     fn is_positive(&self) -> bool {
@@ -91,7 +163,7 @@ pub trait FixedPointValue:
     }
 
     fn is_zero(&self) -> bool {
-        self == &Self::from(0)
+        self == &Self::default()
     }
 
     /// Flips the sign of the value.
@@ -104,7 +176,7 @@ pub trait FixedPointValue:
         if !Self::is_signed() {
             panic!("Cannot flip sign of unsigned type: {self:?}");
         }
-        Self::from(0) - self
+        Self::default() - self
     }
 
     /// Flips the sign of the value if the condition is true.
@@ -128,18 +200,48 @@ pub trait FixedPointValue:
     /// If the absolute value of self overflows `T`, e.g., if self is the
     /// minimum value of a signed integer.
     fn abs(self) -> Self {
-        self.flip_sign_if(self.is_negative())
+        let is_negative = self.is_negative();
+        self.flip_sign_if(is_negative)
     }
 
-    /// Computes the absolute value of self as a `U256` to avoid overflow.
-    fn unsigned_abs(self) -> U256 {
-        if self.is_negative() && self == Self::MIN {
+    /// Computes the absolute value of self as an unsigned value to avoid
+    /// overflow.
+    fn unsigned_abs(self) -> Self::Unsigned {
+        if self.is_negative() && Some(&self) == Self::MIN.as_ref() {
             // Add 1 before flipping the sign to avoid overflow
-            let abs = (self + 1.into()).flip_sign();
-            return abs.to_u256().unwrap() + U256::from(1);
+            let abs = (self + Self::from_u128(1).unwrap()).flip_sign();
+            return abs.to_unsigned().unwrap() + Self::Unsigned::from_u128(1).unwrap();
         }
-        self.abs().to_u256().unwrap()
+        self.abs().to_unsigned().unwrap()
     }
 
-    conversion_fns!(u128, U256);
+    conversion_fns!(u128);
+}
+
+/// An unsigned [`FixedPointValue`] that can hold the absolute values of other
+/// values, e.g., `u64` for `i64` or `U512` for the 256-bit integers.
+pub trait UnsignedValue: FixedPointValue<Unsigned = Self> {
+    /// A type that can hold the full product of two values of `Self`, used for
+    /// intermediate results, e.g., in `mul_div_down`.
+    type Wide: WideValue + From<Self>;
+
+    /// A type with more headroom than [`UnsignedValue::Wide`] for intermediate
+    /// results that outgrow a product, e.g., the series of
+    /// [`Accuracy::Precise`](crate::Accuracy::Precise) logarithms and
+    /// exponentials, which need about twice the digits of `MAX_DECIMALS`.
+    type Extended: WideValue + From<Self>;
+
+    /// Creates a value from little-endian `u64` limbs, returning `None` if it's
+    /// out of range.
+    fn from_limbs(limbs: &[u64]) -> Option<Self>;
+
+    /// Converts self to little-endian `u64` limbs.
+    fn into_limbs(self) -> Vec<u64>;
+
+    /// Converts a wide value to `Self`, returning `None` if it's out of range.
+    fn try_from_wide(value: Self::Wide) -> Option<Self>;
+
+    /// Converts an extended value to `Self`, returning `None` if it's out of
+    /// range.
+    fn try_from_extended(value: Self::Extended) -> Option<Self>;
 }
@@ -1,9 +1,14 @@
-use ethers::types::{I256, U256};
-use eyre::eyre;
+use alloc::{format, vec::Vec};
 
-use crate::{FixedPoint, FixedPointValue};
+#[cfg(feature = "ethers")]
+use ethers::types::I256;
+use primitive_types::{U256, U512};
+
+use crate::{wide::U1024, FixedPoint, FixedPointError, FixedPointValue, Result, UnsignedValue};
 
 /// Implements [`FixedPointValue`] and conversion traits for the given type.
+/// The type must be `Copy` and support `TryFrom` conversions to and from
+/// `U256`, which are used to convert it to and from its `U512` unsigned type.
 ///
 /// # Example
 ///
@@ -24,6 +29,14 @@ use crate::{FixedPoint, FixedPointValue};
 /// - `MAX_DECIMALS`: *(Optional)* The maximum number of decimal places the value can support.
 /// - `from`: *(Optional)* Other types that can convert to the given type.
 /// - `try_from`: *(Optional)* Other types that can try to convert to the given type.
+///
+/// Conversions can also be added separately for a type that already implements
+/// `FixedPointValue`, e.g., when the other type is behind a feature flag.
+///
+/// ```rs
+/// fixed_point_value_impl!(@from U256 => u64);
+/// fixed_point_value_impl!(@try_from U256 => I256);
+/// ```
 #[macro_export]
 macro_rules! fixed_point_value_impl {
   (
@@ -35,99 +48,328 @@ macro_rules! fixed_point_value_impl {
       try_from = $($try_from:ty)|+)?$(,)?
   ) => {
     impl FixedPointValue for $t {
-        const MIN: Self = $min;
-        const MAX: Self = $max;
+        type Unsigned = $crate::U512;
+
+        const MIN: Option<Self> = Some($min);
+        const MAX: Option<Self> = Some($max);
         $(
             const MAX_DECIMALS: u8 = $decimals;
         )?
-    }
 
+        fn from_unsigned(value: $crate::U512) -> $crate::Result<Self> {
+            $crate::U256::try_from(value)
+                .ok()
+                .and_then(|value| Self::try_from(value).ok())
+                .ok_or_else(|| $crate::FixedPointError::OutOfRange {
+                    value: $crate::__format!("{value:?}"),
+                    target: stringify!($t),
+                })
+        }
+
+        fn to_unsigned(self) -> $crate::Result<$crate::U512> {
+            if self.is_negative() {
+                return Err($crate::FixedPointError::NegativeToUnsigned {
+                    value: $crate::__format!("{self:?}"),
+                    target: "U512",
+                });
+            }
+            $crate::U256::try_from(self)
+                .map($crate::U512::from)
+                .map_err(|_| $crate::FixedPointError::OutOfRange {
+                    value: $crate::__format!("{self:?}"),
+                    target: "U512",
+                })
+        }
 
-    impl From<FixedPoint<$t>> for $t {
-        fn from(f: FixedPoint<$t>) -> Self {
+        fn from_u512(value: $crate::U512) -> $crate::Result<Self> {
+            Self::from_unsigned(value)
+        }
+
+        fn to_u512(self) -> $crate::Result<$crate::U512> {
+            self.to_unsigned()
+        }
+    }
+
+    impl<const D: u8> From<FixedPoint<$t, D>> for $t {
+        fn from(f: FixedPoint<$t, D>) -> Self {
             f.raw()
         }
     }
 
     $(
-      $(
-        impl From<$from> for FixedPoint<$t> {
+      $crate::fixed_point_value_impl!(@from $t => $($from)|+);
+    )?
+    $(
+      $crate::fixed_point_value_impl!(@try_from $t => $($try_from)|+);
+    )?
+  };
+
+  // Conversions from other types that always succeed.
+  (@from $t:ty => $($from:ty)|+) => {
+    $(
+        impl<const D: u8> From<$from> for FixedPoint<$t, D> {
             fn from(f: $from) -> Self {
                 FixedPoint::new(f)
             }
         }
 
-        impl TryFrom<FixedPoint<$t>> for $from {
-            type Error = eyre::ErrReport;
-
-            fn try_from(value: FixedPoint<$t>) -> eyre::Result<Self> {
-                value.raw().try_into().map_err(|_| {
-                    eyre!(
-                        "Failed to convert underlying FixedPointValue of type {type} to {to_type}: {value:?}",
-                        type = stringify!($t),
-                        to_type = stringify!($from),
-                        value = value.raw()
-                    )
+        impl<const D: u8> TryFrom<FixedPoint<$t, D>> for $from {
+            type Error = $crate::FixedPointError;
+
+            fn try_from(value: FixedPoint<$t, D>) -> Result<Self, $crate::FixedPointError> {
+                value.raw().try_into().map_err(|_| $crate::FixedPointError::OutOfRange {
+                    value: $crate::__format!("{:?}", value.raw()),
+                    target: stringify!($from),
                 })
             }
           }
-      )+
-    )*
+    )+
+  };
+
+  // Conversions from other types that can fail.
+  (@try_from $t:ty => $($try_from:ty)|+) => {
     $(
-      $(
-        impl TryFrom<$try_from> for FixedPoint<$t> {
-            type Error = eyre::ErrReport;
+        impl<const D: u8> TryFrom<$try_from> for FixedPoint<$t, D> {
+            type Error = $crate::FixedPointError;
 
-            fn try_from(value: $try_from) -> eyre::Result<Self> {
+            fn try_from(value: $try_from) -> Result<Self, $crate::FixedPointError> {
                 FixedPoint::try_from(value)
             }
         }
 
-        impl TryFrom<FixedPoint<$t>> for $try_from {
-            type Error = eyre::ErrReport;
-
-            fn try_from(value: FixedPoint<$t>) -> eyre::Result<Self> {
-                value.raw().try_into().map_err(|_| {
-                    eyre!(
-                        "Failed to convert underlying FixedPointValue of type {type} to {to_type}: {value:?}",
-                        type = stringify!($t),
-                        to_type = stringify!($try_from),
-                        value = value.raw()
-                    )
+        impl<const D: u8> TryFrom<FixedPoint<$t, D>> for $try_from {
+            type Error = $crate::FixedPointError;
+
+            fn try_from(value: FixedPoint<$t, D>) -> Result<Self, $crate::FixedPointError> {
+                value.raw().try_into().map_err(|_| $crate::FixedPointError::OutOfRange {
+                    value: $crate::__format!("{:?}", value.raw()),
+                    target: stringify!($try_from),
                 })
             }
           }
-      )+
-    )*
-  }
+    )+
+  };
 }
 
-fixed_point_value_impl!(
+/// Implements [`FixedPointValue`] for a native integer, using the native
+/// unsigned integer of the same size for its absolute values.
+macro_rules! native_value_impl {
+  (
+      type = $t:ty,
+      Unsigned = $unsigned:ty,
+      MAX_DECIMALS = $decimals:expr$(,
+      from = $($from:ty)|+)?,
+      try_from = $($try_from:ty)|+$(,)?
+  ) => {
+    impl FixedPointValue for $t {
+        type Unsigned = $unsigned;
+
+        const MIN: Option<Self> = Some(<$t>::MIN);
+        const MAX: Option<Self> = Some(<$t>::MAX);
+        const MAX_DECIMALS: u8 = $decimals;
+
+        fn from_unsigned(value: $unsigned) -> Result<Self> {
+            Self::try_from(value).map_err(|_| FixedPointError::OutOfRange {
+                value: format!("{value:?}"),
+                target: stringify!($t),
+            })
+        }
+
+        fn to_unsigned(self) -> Result<$unsigned> {
+            <$unsigned>::try_from(self).map_err(|_| FixedPointError::NegativeToUnsigned {
+                value: format!("{self:?}"),
+                target: stringify!($unsigned),
+            })
+        }
+    }
+
+    impl<const D: u8> From<FixedPoint<$t, D>> for $t {
+        fn from(f: FixedPoint<$t, D>) -> Self {
+            f.raw()
+        }
+    }
+
+    $(
+      fixed_point_value_impl!(@from $t => $($from)|+);
+    )?
+    fixed_point_value_impl!(@try_from $t => $($try_from)|+);
+  };
+}
+
+/// Implements [`UnsignedValue`] for a native unsigned integer with the given
+/// wide type, which holds the full product of two values.
+macro_rules! native_unsigned_value_impl {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl UnsignedValue for $t {
+                type Wide = $wide;
+                type Extended = U1024;
+
+                fn from_limbs(limbs: &[u64]) -> Option<Self> {
+                    let (low, high) = limbs.split_at(limbs.len().min(2));
+                    if high.iter().any(|&limb| limb != 0) {
+                        return None;
+                    }
+                    let value = low
+                        .iter()
+                        .rev()
+                        .fold(0_u128, |value, &limb| value << 64 | u128::from(limb));
+                    Self::try_from(value).ok()
+                }
+
+                fn into_limbs(self) -> Vec<u64> {
+                    let value = u128::from(self);
+                    [value as u64, (value >> 64) as u64].to_vec()
+                }
+
+                fn try_from_wide(value: $wide) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+
+                fn try_from_extended(value: U1024) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+native_value_impl!(
+    type = u32,
+    Unsigned = u32,
+    MAX_DECIMALS = 9,
+    try_from = u64 | i64 | u128 | i128 | U256,
+);
+
+native_value_impl!(
+    type = i64,
+    Unsigned = u64,
+    MAX_DECIMALS = 18,
+    from = u32,
+    try_from = u64 | u128 | i128 | U256,
+);
+
+native_value_impl!(
+    type = u64,
+    Unsigned = u64,
+    MAX_DECIMALS = 19,
+    from = u32,
+    try_from = i64 | u128 | i128 | U256,
+);
+
+native_value_impl!(
     type = i128,
-    MAX = i128::MAX,
-    MIN = i128::MIN,
-    try_from = u128 | I256 | U256,
+    Unsigned = u128,
+    MAX_DECIMALS = 38,
+    from = u32 | i64 | u64,
+    try_from = u128 | U256,
 );
 
-fixed_point_value_impl!(
+native_value_impl!(
     type = u128,
-    MAX = u128::MAX,
-    MIN = u128::MIN,
-    try_from = i128 | I256 | U256,
+    Unsigned = u128,
+    MAX_DECIMALS = 38,
+    from = u32 | u64,
+    try_from = i64 | i128 | U256,
 );
 
+native_unsigned_value_impl!(u32 => u64, u64 => u128, u128 => U256);
+
+fixed_point_value_impl!(
+    type = U256,
+    MAX = U256::MAX,
+    MIN = U256::zero(),
+    MAX_DECIMALS = 77,
+    from = u32 | i64 | u64 | u128,
+    try_from = i128,
+);
+
+// `U512` is the unsigned type of the 256-bit integers, so it's implemented
+// directly instead of converting through `U256`.
+
+impl FixedPointValue for U512 {
+    type Unsigned = U512;
+
+    const MIN: Option<Self> = Some(U512::zero());
+    const MAX: Option<Self> = Some(U512::MAX);
+    const MAX_DECIMALS: u8 = 154;
+
+    fn from_unsigned(value: U512) -> Result<Self> {
+        Ok(value)
+    }
+
+    fn to_unsigned(self) -> Result<U512> {
+        Ok(self)
+    }
+
+    fn from_u512(value: U512) -> Result<Self> {
+        Ok(value)
+    }
+
+    fn to_u512(self) -> Result<U512> {
+        Ok(self)
+    }
+}
+
+impl UnsignedValue for U512 {
+    type Wide = U1024;
+    type Extended = U1024;
+
+    fn from_limbs(limbs: &[u64]) -> Option<Self> {
+        let (low, high) = limbs.split_at(limbs.len().min(8));
+        if high.iter().any(|&limb| limb != 0) {
+            return None;
+        }
+        let mut words = [0; 8];
+        words[..low.len()].copy_from_slice(low);
+        Some(U512(words))
+    }
+
+    fn into_limbs(self) -> Vec<u64> {
+        self.0.to_vec()
+    }
+
+    fn try_from_wide(value: U1024) -> Option<Self> {
+        value.try_into_u512()
+    }
+
+    fn try_from_extended(value: U1024) -> Option<Self> {
+        value.try_into_u512()
+    }
+}
+
+impl<const D: u8> From<FixedPoint<U512, D>> for U512 {
+    fn from(f: FixedPoint<U512, D>) -> Self {
+        f.raw()
+    }
+}
+
+fixed_point_value_impl!(@from U512 => u32 | i64 | u64 | u128 | U256);
+fixed_point_value_impl!(@try_from U512 => i128);
+fixed_point_value_impl!(@try_from u32 => U512);
+fixed_point_value_impl!(@try_from i64 => U512);
+fixed_point_value_impl!(@try_from u64 => U512);
+fixed_point_value_impl!(@try_from i128 => U512);
+fixed_point_value_impl!(@try_from u128 => U512);
+fixed_point_value_impl!(@try_from U256 => U512);
+
+// The signed 256-bit type is only available with the `ethers` feature.
+
+#[cfg(feature = "ethers")]
 fixed_point_value_impl!(
     type = I256,
     MAX = I256::MAX,
     MIN = I256::MIN,
-    from = i128 | u128,
+    MAX_DECIMALS = 76,
+    from = u32 | i64 | u64 | i128 | u128,
     try_from = U256,
 );
 
-fixed_point_value_impl!(
-    type = U256,
-    MAX = U256::MAX,
-    MIN = U256::zero(),
-    from = u128,
-    try_from = i128 | I256,
-);
+#[cfg(feature = "ethers")]
+fixed_point_value_impl!(@try_from i128 => I256);
+
+#[cfg(feature = "ethers")]
+fixed_point_value_impl!(@try_from u128 => I256);
+
+#[cfg(feature = "ethers")]
+fixed_point_value_impl!(@try_from U256 => I256);
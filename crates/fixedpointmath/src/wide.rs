@@ -0,0 +1,247 @@
+use core::{
+    fmt::{Debug, Display},
+    ops::{Add, Mul, Rem, Sub},
+};
+
+use primitive_types::{U256, U512};
+
+pub(crate) use self::u1024::U1024;
+use crate::{FixedPointValue, UnsignedValue};
+
+/// The wide type used for intermediate results of math on the absolute values
+/// of `T`.
+pub(crate) type Wide<T> = <<T as FixedPointValue>::Unsigned as UnsignedValue>::Wide;
+
+/// The extended type used for intermediate results of math on the absolute
+/// values of `T` that don't fit in [`Wide`].
+pub(crate) type Extended<T> = <<T as FixedPointValue>::Unsigned as UnsignedValue>::Extended;
+
+/// An unsigned integer used for intermediate results of math on the absolute
+/// values of `FixedPoint` numbers, see [`UnsignedValue::Wide`].
+pub trait WideValue:
+    Clone
+    + Debug
+    + Display
+    + Ord
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Rem<Output = Self>
+{
+    fn one() -> Self;
+
+    fn from_u64(value: u64) -> Self;
+
+    /// Computes `10` to the power of `n`.
+    fn exp10(n: usize) -> Self;
+
+    /// Computes `2` to the power of `n`.
+    fn pow2(n: usize) -> Self;
+
+    fn is_zero(&self) -> bool;
+
+    fn is_odd(&self) -> bool;
+
+    /// The number of bits needed to represent the value, i.e., `0` for `0`.
+    fn bits(&self) -> usize;
+
+    /// The least significant 64 bits of the value.
+    fn low_u64(&self) -> u64;
+
+    /// Multiplies two values, returning `None` if the product overflows.
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Computes the quotient and remainder of `self / other`.
+    fn div_mod(self, other: Self) -> (Self, Self);
+}
+
+// The code generated by `construct_uint!` doesn't pass clippy's lints.
+#[allow(clippy::all)]
+mod u1024 {
+    use uint::construct_uint;
+
+    construct_uint! {
+        /// A 1024-bit unsigned integer used for intermediate results of math
+        /// on the `U512` absolute values of `FixedPoint` numbers, e.g., the
+        /// full product in `mul_div_down`.
+        pub struct U1024(16);
+    }
+}
+
+impl U1024 {
+    /// Multiplies two `U512`s without overflow.
+    pub fn full_mul(lhs: U512, rhs: U512) -> Self {
+        Self::from(lhs) * Self::from(rhs)
+    }
+
+    /// Converts this instance into a `U512`, returning `None` if it's too
+    /// large.
+    pub fn try_into_u512(self) -> Option<U512> {
+        if self.0[8..].iter().any(|&word| word != 0) {
+            return None;
+        }
+        let mut words = [0; 8];
+        words.copy_from_slice(&self.0[..8]);
+        Some(U512(words))
+    }
+}
+
+impl From<U512> for U1024 {
+    fn from(value: U512) -> Self {
+        let mut words = [0; 16];
+        words[..8].copy_from_slice(&value.0);
+        U1024(words)
+    }
+}
+
+impl WideValue for U1024 {
+    fn one() -> Self {
+        U1024::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        U1024::from(value)
+    }
+
+    fn exp10(n: usize) -> Self {
+        U1024::exp10(n)
+    }
+
+    fn pow2(n: usize) -> Self {
+        U1024::one() << n
+    }
+
+    fn is_zero(&self) -> bool {
+        U1024::is_zero(self)
+    }
+
+    fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    fn bits(&self) -> usize {
+        U1024::bits(self)
+    }
+
+    fn low_u64(&self) -> u64 {
+        U1024::low_u64(self)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        U1024::checked_mul(self, other)
+    }
+
+    fn div_mod(self, other: Self) -> (Self, Self) {
+        U1024::div_mod(self, other)
+    }
+}
+
+impl WideValue for U256 {
+    fn one() -> Self {
+        U256::one()
+    }
+
+    fn from_u64(value: u64) -> Self {
+        U256::from(value)
+    }
+
+    fn exp10(n: usize) -> Self {
+        U256::exp10(n)
+    }
+
+    fn pow2(n: usize) -> Self {
+        U256::one() << n
+    }
+
+    fn is_zero(&self) -> bool {
+        U256::is_zero(self)
+    }
+
+    fn is_odd(&self) -> bool {
+        self.bit(0)
+    }
+
+    fn bits(&self) -> usize {
+        U256::bits(self)
+    }
+
+    fn low_u64(&self) -> u64 {
+        U256::low_u64(self)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        U256::checked_mul(self, other)
+    }
+
+    fn div_mod(self, other: Self) -> (Self, Self) {
+        U256::div_mod(self, other)
+    }
+}
+
+/// Implements [`WideValue`] for native unsigned integers.
+macro_rules! native_wide_value_impl {
+    ($($t:ty),*) => {
+        $(
+            impl WideValue for $t {
+                fn one() -> Self {
+                    1
+                }
+
+                fn from_u64(value: u64) -> Self {
+                    value.into()
+                }
+
+                fn exp10(n: usize) -> Self {
+                    <$t>::pow(10, n as u32)
+                }
+
+                fn pow2(n: usize) -> Self {
+                    1 << n
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+
+                fn is_odd(&self) -> bool {
+                    self % 2 == 1
+                }
+
+                fn bits(&self) -> usize {
+                    (<$t>::BITS - self.leading_zeros()) as usize
+                }
+
+                fn low_u64(&self) -> u64 {
+                    *self as u64
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn div_mod(self, other: Self) -> (Self, Self) {
+                    (self / other, self % other)
+                }
+            }
+        )*
+    };
+}
+
+native_wide_value_impl!(u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_full_mul() {
+        let product = U1024::full_mul(U512::MAX, U512::MAX);
+        // (2^512 - 1)^2 = 2^1024 - 2^513 + 1
+        assert_eq!(product, U1024::MAX - (U1024::from(U512::MAX) << 1));
+        assert_eq!(product.try_into_u512(), None);
+        assert_eq!(
+            U1024::full_mul(U512::MAX, U512::one()).try_into_u512(),
+            Some(U512::MAX)
+        );
+    }
+}
@@ -1,5 +1,6 @@
+use fixedpointmath::{format as fallback, RoundingMode};
+use js_sys::{global, Array, Intl, JsString, Object, Reflect};
 use utils_core::error::{Error, ToResult};
-use js_sys::{Array, Intl, JsString, Object, Reflect};
 use ts_macro::ts;
 use wasm_bindgen::prelude::*;
 
@@ -22,6 +23,19 @@ impl WasmFixedPoint {
             base_options.decimals = options.decimals().or(Some(self.decimals));
         }
 
+        // format without Intl if it's not available
+        if !has_intl() {
+            let fallback_options = fallback::FormatOptions {
+                base: base_options.to_fallback(),
+                compact_display: options.compact_display().map(|mode| match mode.as_str() {
+                    "long" => fallback::CompactDisplay::Long,
+                    _ => fallback::CompactDisplay::Short,
+                }),
+                percent: options.percent() == Some(true),
+            };
+            return Ok(self.inner.format(&fallback_options).into());
+        }
+
         // get base format args
         let FormatArgs {
             locales_arr,
@@ -57,6 +71,22 @@ impl WasmFixedPoint {
         // parse base options
         let base_options = options.obj.parse();
 
+        // format without Intl if it's not available
+        if !has_intl() {
+            let fallback_options = fallback::CurrencyFormatOptions {
+                base: base_options.to_fallback(),
+                currency: options.currency().unwrap_or("USD".into()),
+                display: match options.display().as_deref() {
+                    Some("narrowSymbol") => fallback::CurrencyDisplay::NarrowSymbol,
+                    Some("code") => fallback::CurrencyDisplay::Code,
+                    Some("name") => fallback::CurrencyDisplay::Name,
+                    _ => fallback::CurrencyDisplay::Symbol,
+                },
+                compact: options.compact() == Some(true),
+            };
+            return Ok(self.inner.format_currency(&fallback_options).into());
+        }
+
         // get base format args
         let FormatArgs {
             locales_arr,
@@ -128,6 +158,11 @@ impl WasmFixedPoint {
     }
 }
 
+/// Whether the JavaScript runtime provides `Intl`.
+fn has_intl() -> bool {
+    Reflect::has(&global(), &"Intl".into()).unwrap_or(false)
+}
+
 // Types //
 
 #[ts]
@@ -220,6 +255,35 @@ struct BaseFormatOptions {
     group: Option<bool>,
 }
 
+impl BaseFormatOptions {
+    /// Converts the options to the options of the Intl-free formatter in
+    /// `fixedpointmath`.
+    fn to_fallback(&self) -> fallback::BaseFormatOptions {
+        let rounding = match self.rounding.as_deref() {
+            Some("ceil") => RoundingMode::Ceil,
+            Some("floor") => RoundingMode::Floor,
+            Some("expand") => RoundingMode::AwayFromZero,
+            Some("trunc") => RoundingMode::TowardZero,
+            Some("halfCeil") => RoundingMode::HalfCeil,
+            Some("halfFloor") => RoundingMode::HalfFloor,
+            Some("halfTrunc") => RoundingMode::HalfDown,
+            Some("halfEven") => RoundingMode::HalfEven,
+            _ => RoundingMode::HalfUp,
+        };
+        fallback::BaseFormatOptions {
+            decimals: self.decimals,
+            trailing_zeros: self.trailing_zeros == Some(true),
+            rounding,
+            locale: self
+                .locale
+                .as_deref()
+                .and_then(fallback::Locale::from_tag)
+                .unwrap_or_default(),
+            group: self.group != Some(false),
+        }
+    }
+}
+
 impl Default for IBaseFormatOptions {
     fn default() -> Self {
         IBaseFormatOptions {
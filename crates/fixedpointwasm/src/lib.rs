@@ -10,7 +10,7 @@ use utils_core::{
 };
 use ethers::types::I256;
 use fixedpointmath::{i256_from_str, prelude::*};
-use js_sys::{parse_float, BigInt, JsString};
+use js_sys::{BigInt, JsString};
 use rand::{thread_rng, Rng};
 use ts_macro::ts;
 use wasm_bindgen::prelude::*;
@@ -413,7 +413,8 @@ impl WasmFixedPoint {
     /// ```
     #[wasm_bindgen(skip_jsdoc, js_name = toNumber)]
     pub fn to_number(&self) -> f64 {
-        parse_float(&self.to_string())
+        let scaled = self.inner / WasmFixedPoint::scale_factor(self.decimals);
+        (scaled * WasmFixedPoint::scale_factor(self.decimals)).to_f64()
     }
 
     #[wasm_bindgen(skip_jsdoc, js_name = valueOf)]
@@ -495,27 +496,13 @@ impl TryFrom<Numberish> for FixedPoint<I256> {
         match num.js_typeof().as_string().unwrap_or_default().as_str() {
             "bigint" | "number" => Ok(num.to_i256()?.fixed()),
             _ => {
-                let mut s = num
+                let s = num
                     .to_string()
                     .trim()
-                    .to_lower_case()
-                    .replace_all("_", "")
-                    .replace_all(",", "");
-                let mut sign = 1;
-                if s.starts_with("-", 0) {
-                    s = s.slice(1, s.length());
-                    sign = -1;
-                };
-                if s.starts_with("+", 0) {
-                    s = s.slice(1, s.length());
-                };
-                if s.starts_with("0x", 0) || s.starts_with("0b", 0) || s.starts_with("0o", 0) {
-                    let int = s.to_i256()? * I256::from(sign);
-                    return Ok(int.fixed());
-                };
-                let s = s.as_string().unwrap_or_default();
-                let int = i256_from_str(&s).to_result()? * I256::from(sign);
-                Ok(int.fixed())
+                    .replace_all(",", "")
+                    .as_string()
+                    .unwrap_or_default();
+                Ok(i256_from_str(&s).to_result()?.fixed())
             }
         }
     }